@@ -0,0 +1,568 @@
+//! Animated garnishes
+//!
+//! Animated garnishes derive their frame from the `tick` of the
+//! [`RenderContext`]. Render with `render_at`, or `render_with_context`
+//! and [`RenderContext::tick`], and increase the tick every frame. Without
+//! a tick, e.g. when rendered with `render`, the first frame is shown.
+//! Other garnishes ignore the tick.
+//!
+//! - [`Frames`] cycles through a list of garnishes, e.g. titles with a spinner.
+//! - [`MarchingAnts`] is a dashed border whose dashes move along the border.
+//! - [`Pulse`] is a `Style` whose color pulses between two colors.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::{buffer::Buffer, layout::Rect, style::Color, text::Line};
+//! use ratatui_garnish::{
+//!     GarnishableWidget,
+//!     animation::{Frames, MarchingAnts, Pulse},
+//!     title::{Title, Top},
+//! };
+//!
+//! let spinner = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+//!
+//! let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 3));
+//! for tick in 0..100 {
+//!     Line::raw("Downloading")
+//!         .garnish(Frames::new(spinner.map(|frame| Title::<Top>::raw(frame).margin(1))))
+//!         .garnish(Pulse::new(Color::Blue, Color::Cyan).period(30))
+//!         .garnish(MarchingAnts::default())
+//!         .render_at(buffer.area, &mut buffer, tick);
+//! }
+//! ```
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::Color,
+};
+
+use crate::{
+    ColorDepth, Garnish, HitPart, RenderContext, RenderModifier,
+    border::{BorderSet, Borders, CustomBorder},
+    color,
+    gradient::GradientTarget,
+};
+
+// ===== Frames =====
+
+/// Cycles through a list of garnishes, showing one per frame.
+///
+/// Frames may take different space. Layout queries without a context,
+/// like `inner_area`, use the first frame; the queries ending in `_with`,
+/// like `inner_area_with`, use the frame shown at the tick of the context.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_garnish::{animation::Frames, title::{Title, Bottom}};
+///
+/// let frames = Frames::new(["◐", "◓", "◑", "◒"].map(Title::<Bottom>::raw)).ticks_per_frame(3);
+/// assert_eq!(frames.frame(5).and_then(|frame| frame.try_as_title_bottom_ref()),
+///     Some(&Title::<Bottom>::raw("◓")));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Debug, Clone)]
+pub struct Frames<'a> {
+    frames: Vec<Garnish<'a>>,
+    ticks_per_frame: u32,
+}
+
+impl Default for Frames<'_> {
+    /// Creates an animation without frames, showing each frame for one tick.
+    fn default() -> Self {
+        Self {
+            frames: Vec::new(),
+            ticks_per_frame: 1,
+        }
+    }
+}
+
+impl<'a> Frames<'a> {
+    /// Creates an animation of `frames`, showing each frame for one tick.
+    #[must_use = "constructor returns a new instance"]
+    pub fn new<I>(frames: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Garnish<'a>>,
+    {
+        Self {
+            frames: frames.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the number of ticks each frame is shown.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn ticks_per_frame(mut self, ticks_per_frame: u32) -> Self {
+        self.ticks_per_frame = ticks_per_frame;
+        self
+    }
+
+    /// Returns the frames.
+    #[must_use]
+    pub fn frames(&self) -> &[Garnish<'a>] {
+        &self.frames
+    }
+
+    /// Returns the frame shown at `tick`, `None` if there are no frames.
+    #[must_use]
+    pub fn frame(&self, tick: u64) -> Option<&Garnish<'a>> {
+        let step = tick / u64::from(self.ticks_per_frame.max(1));
+        let index = step % u64::try_from(self.frames.len()).ok()?.max(1);
+        self.frames.get(usize::try_from(index).ok()?)
+    }
+}
+
+impl RenderModifier for Frames<'_> {
+    fn modify_area(&self, area: Rect) -> Rect {
+        self.modify_area_with(area, &RenderContext::default())
+    }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        self.expand_area_with(area, &RenderContext::default())
+    }
+
+    fn before_render(&self, area: Rect, buf: &mut Buffer) {
+        self.before_render_with(area, buf, &RenderContext::default());
+    }
+
+    fn after_render(&self, area: Rect, buf: &mut Buffer) {
+        self.after_render_with(area, buf, &RenderContext::default());
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        self.hit_test_with(area, position, &RenderContext::default())
+    }
+
+    fn modify_area_with(&self, area: Rect, context: &RenderContext) -> Rect {
+        self.frame(context.tick)
+            .map_or(area, |frame| frame.modify_area_with(area, context))
+    }
+
+    fn expand_area_with(&self, area: Rect, context: &RenderContext) -> Rect {
+        self.frame(context.tick)
+            .map_or(area, |frame| frame.expand_area_with(area, context))
+    }
+
+    fn before_render_with(&self, area: Rect, buf: &mut Buffer, context: &RenderContext) {
+        if let Some(frame) = self.frame(context.tick) {
+            frame.before_render_with(area, buf, context);
+        }
+    }
+
+    fn after_render_with(&self, area: Rect, buf: &mut Buffer, context: &RenderContext) {
+        if let Some(frame) = self.frame(context.tick) {
+            frame.after_render_with(area, buf, context);
+        }
+    }
+
+    fn hit_test_with(
+        &self,
+        area: Rect,
+        position: Position,
+        context: &RenderContext,
+    ) -> Option<HitPart> {
+        self.frame(context.tick)
+            .and_then(|frame| frame.hit_test_with(area, position, context))
+    }
+}
+
+// ===== Marching Ants =====
+
+/// A dashed border whose dashes move clockwise along the border.
+///
+/// The sides are drawn with the characters of the `char_set`, alternating
+/// `dash` cells of line with `gap` cells of `gap_symbol`. Every
+/// `ticks_per_step` ticks the pattern moves one cell. Corners are always
+/// drawn.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect};
+/// use ratatui_garnish::{RenderContext, RenderModifier, animation::MarchingAnts};
+///
+/// let ants = MarchingAnts::default().dash(2).gap(1);
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+/// ants.before_render_with(buffer.area, &mut buffer, &RenderContext::new().tick(1));
+/// assert_eq!(buffer, Buffer::with_lines(["┌── ── ┐", "└── ── ┘"]));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct MarchingAnts {
+    /// The characters used to draw the border, defaults to `BorderSet::plain()`.
+    pub char_set: BorderSet,
+    /// Which borders to draw, defaults to `Borders::ALL`.
+    pub borders: Borders,
+    /// The length of a dash, defaults to 2.
+    pub dash: u8,
+    /// The length of a gap, defaults to 2.
+    pub gap: u8,
+    /// The character drawn in gaps, defaults to a space.
+    pub gap_symbol: char,
+    /// The number of ticks before the dashes move one cell, defaults to 1.
+    pub ticks_per_step: u32,
+}
+
+impl Default for MarchingAnts {
+    /// Creates a plain border with all sides and dashes and gaps of 2 cells.
+    fn default() -> Self {
+        Self {
+            char_set: BorderSet::plain(),
+            borders: Borders::ALL,
+            dash: 2,
+            gap: 2,
+            gap_symbol: ' ',
+            ticks_per_step: 1,
+        }
+    }
+}
+
+impl MarchingAnts {
+    /// Creates marching ants drawn with `char_set`.
+    #[must_use = "constructor returns a new instance"]
+    pub fn new(char_set: BorderSet) -> Self {
+        Self {
+            char_set,
+            ..Default::default()
+        }
+    }
+
+    /// Sets which borders to draw.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn borders(mut self, borders: Borders) -> Self {
+        self.borders = borders;
+        self
+    }
+
+    /// Sets the length of a dash.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn dash(mut self, dash: u8) -> Self {
+        self.dash = dash;
+        self
+    }
+
+    /// Sets the length of a gap.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn gap(mut self, gap: u8) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the character drawn in gaps.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn gap_symbol(mut self, gap_symbol: char) -> Self {
+        self.gap_symbol = gap_symbol;
+        self
+    }
+
+    /// Sets the number of ticks before the dashes move one cell.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn ticks_per_step(mut self, ticks_per_step: u32) -> Self {
+        self.ticks_per_step = ticks_per_step;
+        self
+    }
+
+    /// Returns the border drawn without gaps.
+    fn border(&self) -> CustomBorder {
+        CustomBorder::new(self.char_set).borders(self.borders)
+    }
+
+    /// Returns `true` if the cell `index` cells clockwise from the top left
+    /// corner is part of a gap at `tick`.
+    fn is_gap(&self, index: u64, tick: u64) -> bool {
+        let period = u64::from(self.dash) + u64::from(self.gap);
+        if period == 0 {
+            return false;
+        }
+        let step = (tick / u64::from(self.ticks_per_step.max(1))) % period;
+        (index + period - step) % period >= u64::from(self.dash)
+    }
+}
+
+impl RenderModifier for MarchingAnts {
+    fn modify_area(&self, area: Rect) -> Rect {
+        self.border().modify_area(area)
+    }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        self.border().expand_area(area)
+    }
+
+    fn before_render(&self, area: Rect, buf: &mut Buffer) {
+        self.before_render_with(area, buf, &RenderContext::default());
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        self.border().hit_test(area, position)
+    }
+
+    fn before_render_with(&self, area: Rect, buf: &mut Buffer, context: &RenderContext) {
+        self.border().before_render(area, buf);
+        if area.is_empty() {
+            return;
+        }
+
+        let (right, bottom) = (area.width - 1, area.height - 1);
+        for position in area.intersection(buf.area).positions() {
+            let (x, y) = (position.x - area.x, position.y - area.y);
+            let mut sides = Borders::NONE;
+            sides.set(Borders::LEFT, x == 0);
+            sides.set(Borders::TOP, y == 0);
+            sides.set(Borders::RIGHT, x == right);
+            sides.set(Borders::BOTTOM, y == bottom);
+            // corners have two sides, and are always drawn
+            if sides.bits().count_ones() != 1 || !self.borders.contains(sides) {
+                continue;
+            }
+
+            let (x, y, right, bottom) = (
+                u64::from(x),
+                u64::from(y),
+                u64::from(right),
+                u64::from(bottom),
+            );
+            let index = if sides == Borders::TOP {
+                x
+            } else if sides == Borders::RIGHT {
+                right + y
+            } else if sides == Borders::BOTTOM {
+                right + bottom + (right - x)
+            } else {
+                2 * right + bottom + (bottom - y)
+            };
+            if self.is_gap(index, context.tick) {
+                buf[position].set_char(self.gap_symbol);
+            }
+        }
+    }
+}
+
+// ===== Pulse =====
+
+/// A style whose color pulses between two colors.
+///
+/// Like a `Style` garnish it colors the whole area. The color goes from
+/// `start` to `end` and back in `period` ticks.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Color;
+/// use ratatui_garnish::{animation::Pulse, gradient::GradientTarget};
+///
+/// let pulse = Pulse::new(Color::Rgb(0, 0, 0), Color::Rgb(200, 200, 200))
+///     .period(4)
+///     .target(GradientTarget::Background);
+/// assert_eq!(pulse.color(0), Color::Rgb(0, 0, 0));
+/// assert_eq!(pulse.color(1), Color::Rgb(100, 100, 100));
+/// assert_eq!(pulse.color(2), Color::Rgb(200, 200, 200));
+/// assert_eq!(pulse.color(3), Color::Rgb(100, 100, 100));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Pulse {
+    /// The color at the start of the pulse.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_color")
+    )]
+    pub start: Color,
+    /// The color halfway the pulse.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_color")
+    )]
+    pub end: Color,
+    /// The number of ticks of one pulse, defaults to 20.
+    pub period: u32,
+    /// Which colors to set, defaults to `Foreground`.
+    pub target: GradientTarget,
+    /// The colors available, defaults to `TrueColor`.
+    pub color_depth: ColorDepth,
+}
+
+impl Default for Pulse {
+    /// Creates a foreground pulse from `Color::Reset` to `Color::Reset` in 20 ticks.
+    fn default() -> Self {
+        Self {
+            start: Color::Reset,
+            end: Color::Reset,
+            period: 20,
+            target: GradientTarget::Foreground,
+            color_depth: ColorDepth::TrueColor,
+        }
+    }
+}
+
+impl Pulse {
+    /// Creates a foreground pulse from `start` to `end` in 20 ticks.
+    #[must_use = "constructor returns a new instance"]
+    pub fn new(start: Color, end: Color) -> Self {
+        Self {
+            start,
+            end,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the number of ticks of one pulse.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn period(mut self, period: u32) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Sets which colors of the cells the pulse sets.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn target(mut self, target: GradientTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Sets the colors available to the pulse.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = color_depth;
+        self
+    }
+
+    /// Returns the color of the pulse at `tick`.
+    #[must_use]
+    pub fn color(&self, tick: u64) -> Color {
+        let period = u64::from(self.period.max(1));
+        // both values are less than `u32::MAX`, no precision is lost
+        #[allow(clippy::cast_precision_loss)]
+        let phase = (tick % period) as f32 / period as f32;
+        let t = 1.0 - (2.0 * phase - 1.0).abs();
+        color::interpolate(self.start, self.end, t, self.color_depth)
+    }
+}
+
+impl RenderModifier for Pulse {
+    fn before_render(&self, area: Rect, buf: &mut Buffer) {
+        self.before_render_with(area, buf, &RenderContext::default());
+    }
+
+    fn before_render_with(&self, area: Rect, buf: &mut Buffer, context: &RenderContext) {
+        buf.set_style(area, self.target.style(self.color(context.tick)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        GarnishLayout, GarnishableWidget, Hit, Padding,
+        title::{Title, Top},
+    };
+    use ratatui::{text::Line, widgets::WidgetRef};
+
+    #[test]
+    fn frames_cycle_with_tick() {
+        let widget = Line::raw("Test")
+            .garnish(Frames::new(["a", "b", "c"].map(Title::<Top>::raw)).ticks_per_frame(2))
+            .garnish(Padding::top(1));
+        let area = Rect::new(0, 0, 4, 2);
+
+        let render = |tick| {
+            let mut buffer = Buffer::empty(area);
+            widget.render_ref_at(area, &mut buffer, tick);
+            buffer[(0, 0)].symbol().to_string()
+        };
+        assert_eq!(render(0), "a");
+        assert_eq!(render(3), "b");
+        assert_eq!(render(5), "c");
+        assert_eq!(render(7), "a");
+
+        let mut buffer = Buffer::empty(area);
+        widget.render_ref(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["a", "Test"]));
+        assert!(Frames::default().frame(3).is_none());
+    }
+
+    #[test]
+    fn frames_queries_follow_tick() {
+        let widget = Line::raw("Test").garnish(Frames::new([Padding::top(1), Padding::top(2)]));
+        let area = Rect::new(0, 0, 4, 4);
+        let second = RenderContext::new().tick(1);
+
+        assert_eq!(widget.inner_area(area), Rect::new(0, 1, 4, 3));
+        assert_eq!(widget.inner_area_with(area, &second), Rect::new(0, 2, 4, 2));
+        assert_eq!(widget.outer_area_with(Rect::new(0, 2, 4, 2), &second), area);
+        assert_eq!(
+            widget.hit_test(area, Position::new(0, 1)),
+            Some(Hit::Widget)
+        );
+        assert_eq!(
+            widget.hit_test_with(area, Position::new(0, 1), &second),
+            Some(Hit::Garnish {
+                index: 0,
+                part: HitPart::Area
+            })
+        );
+
+        let mut buffer = Buffer::empty(area);
+        widget.render_ref_at(area, &mut buffer, 1);
+        assert_eq!(buffer[(0, 2)].symbol(), "T");
+    }
+
+    #[test]
+    fn marching_ants_move_clockwise() {
+        let ants = MarchingAnts::default().gap_symbol('·');
+        let area = Rect::new(0, 0, 6, 3);
+
+        let render = |tick| {
+            let mut buffer = Buffer::empty(area);
+            ants.before_render_with(area, &mut buffer, &RenderContext::new().tick(tick));
+            buffer
+        };
+        assert_eq!(
+            render(0),
+            Buffer::with_lines(["┌─··─┐", "│    ·", "└··──┘"])
+        );
+        assert_eq!(
+            render(1),
+            Buffer::with_lines(["┌──··┐", "│    │", "└·──·┘"])
+        );
+        assert_eq!(render(4), render(0));
+        assert_eq!(ants.modify_area(area), Rect::new(1, 1, 4, 1));
+    }
+
+    #[test]
+    fn pulse_colors_area() {
+        let pulse = Pulse::new(Color::Rgb(0, 0, 0), Color::Rgb(0, 100, 0))
+            .period(10)
+            .target(GradientTarget::Background);
+        let area = Rect::new(0, 0, 2, 1);
+
+        let mut buffer = Buffer::empty(area);
+        pulse.before_render_with(area, &mut buffer, &RenderContext::new().tick(15));
+        assert_eq!(buffer[(1, 0)].bg, Color::Rgb(0, 100, 0));
+        assert_eq!(buffer[(1, 0)].fg, Color::Reset);
+
+        pulse.before_render(area, &mut buffer);
+        assert_eq!(buffer[(1, 0)].bg, Color::Rgb(0, 0, 0));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn animation_serialization() {
+        let frames = Frames::new([Pulse::new(Color::Red, Color::Blue)]).ticks_per_frame(4);
+        let json = serde_json::to_string(&frames).unwrap();
+        let restored: Frames = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.frames().len(), 1);
+        assert_eq!(
+            restored.frame(0).and_then(|frame| frame.try_as_pulse_ref()),
+            Some(&Pulse::new(Color::Red, Color::Blue))
+        );
+
+        let ants: MarchingAnts = serde_json::from_str(r#"{ "dash": 3 }"#).unwrap();
+        assert_eq!(ants, MarchingAnts::default().dash(3));
+    }
+}
@@ -2,8 +2,9 @@
 //!
 //! A border is composed of a set of characters used to draw the border,
 //! a `BorderSet` and a bitflags struct [`Borders`] (just like in `ratatui`) to
-//! configure which borders to render. Borders don't have their own [`Style`](ratatui::style::Style);
-//! set the style of your border with the `Style` garnish instead.
+//! configure which borders to render. Borders inherit the style of the
+//! area they are drawn in, so set the style of your border with a preceding
+//! `Style` garnish, or use [`BorderStyles`] to style only the border cells.
 //!
 //! # Example
 //! ```rust
@@ -72,9 +73,66 @@
 //!
 //! let border = CustomBorder::new(BorderSet::plain().corners('*'));
 //! ```
+//!
+//! # Styling Borders
+//!
+//! A `Style` garnish styles the whole area, including the widget inside
+//! the border. [`BorderStyles`] sets optional styles per side and corner
+//! that only touch the border cells. Standard borders are converted into a
+//! [`CustomBorder`] with `styles`:
+//!
+//! ```rust
+//! use ratatui::{style::{Color, Style}, text::Text};
+//! use ratatui_garnish::GarnishableWidget;
+//! use ratatui_garnish::border::{BorderStyles, PlainBorder};
+//!
+//! let grey = Style::default().fg(Color::Gray);
+//! let widget = Text::raw("Hello, world").garnish(PlainBorder::default().styles(BorderStyles {
+//!     top: Some(Style::default().fg(Color::Red)),
+//!     ..BorderStyles::new(grey)
+//! }));
+//! ```
+//!
+//! # Merging Borders
+//!
+//! When widgets are laid out next to each other their borders are drawn
+//! as two parallel lines or overwrite each other's corners. A border with
+//! a [`MergeStrategy`] other than `Replace` inspects the characters already
+//! in the buffer and draws junctions like `┬ ┴ ├ ┤ ┼` instead. Standard
+//! borders are converted into a [`CustomBorder`] with `merge`:
+//!
+//! ```rust
+//! use ratatui::text::Text;
+//! use ratatui_garnish::GarnishableWidget;
+//! use ratatui_garnish::border::{MergeStrategy, PlainBorder, ThickBorder};
+//!
+//! let left = Text::raw("left")
+//!     .garnish(PlainBorder::default().merge(MergeStrategy::Exact));
+//! let right = Text::raw("right")
+//!     .garnish(ThickBorder::default().merge(MergeStrategy::Exact));
+//! ```
+//!
+//! # Collapsed Borders
+//!
+//! [`BorderGrid`] lays out a grid of cells that share their border lines,
+//! instead of wasting a column or row between every pair of neighbours:
+//!
+//! ```text
+//! ┌───┬───┐
+//! │   │   │
+//! ├───┼───┤
+//! │   │   │
+//! └───┴───┘
+//! ```
 
+use crate::{Garnish, GarnishedWidget, HitPart};
 use derive_more::{Deref, DerefMut};
-use ratatui::{buffer::Buffer, layout::Rect};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Layout, Position, Rect},
+    style::Style,
+    widgets::Widget,
+};
 
 /// Trait for rendering borders around ratatui widgets.
 ///
@@ -120,6 +178,20 @@ pub trait Border {
         }
     }
 
+    /// Returns how the border combines with characters already in the buffer.
+    ///
+    /// Defaults to [`MergeStrategy::Replace`].
+    fn merge_strategy(&self) -> MergeStrategy {
+        MergeStrategy::Replace
+    }
+
+    /// Returns the styles applied to the border cells.
+    ///
+    /// Defaults to no styles, the border inherits the style of the area.
+    fn get_border_styles(&self) -> BorderStyles {
+        BorderStyles::default()
+    }
+
     /// Renders the corner characters if adjacent sides are present.
     fn render_corners(&self, area: Rect, buffer: &mut Buffer, charset: &BorderSet) {
         let borders = self.get_borders();
@@ -145,47 +217,538 @@ pub trait Border {
 
 impl<T: Border> crate::RenderModifier for T {
     fn before_render(&self, area: Rect, buffer: &mut Buffer) {
+        render_border(self, area, buffer, self.merge_strategy());
+    }
+
+    fn modify_area(&self, area: Rect) -> Rect {
+        let mut inner = area;
         let borders = self.get_borders();
-        let border_set = self.get_border_set();
 
         if borders.contains(Borders::LEFT) {
-            self.render_left(area, buffer, border_set.left);
+            inner.x = inner.x.saturating_add(1).min(inner.right());
+            inner.width = inner.width.saturating_sub(1);
         }
         if borders.contains(Borders::TOP) {
-            self.render_top(area, buffer, border_set.top);
+            inner.y = inner.y.saturating_add(1).min(inner.bottom());
+            inner.height = inner.height.saturating_sub(1);
         }
         if borders.contains(Borders::RIGHT) {
-            self.render_right(area, buffer, border_set.right);
+            inner.width = inner.width.saturating_sub(1);
         }
         if borders.contains(Borders::BOTTOM) {
-            self.render_bottom(area, buffer, border_set.bottom);
+            inner.height = inner.height.saturating_sub(1);
         }
-
-        self.render_corners(area, buffer, &border_set);
+        inner
     }
 
-    fn modify_area(&self, area: Rect) -> Rect {
-        let mut inner = area;
+    fn expand_area(&self, area: Rect) -> Rect {
+        let mut outer = area;
         let borders = self.get_borders();
 
         if borders.contains(Borders::LEFT) {
-            inner.x = inner.x.saturating_add(1).min(inner.right());
-            inner.width = inner.width.saturating_sub(1);
+            outer.x = outer.x.saturating_sub(1);
+            outer.width = outer.width.saturating_add(1);
         }
         if borders.contains(Borders::TOP) {
-            inner.y = inner.y.saturating_add(1).min(inner.bottom());
-            inner.height = inner.height.saturating_sub(1);
+            outer.y = outer.y.saturating_sub(1);
+            outer.height = outer.height.saturating_add(1);
         }
         if borders.contains(Borders::RIGHT) {
-            inner.width = inner.width.saturating_sub(1);
+            outer.width = outer.width.saturating_add(1);
         }
         if borders.contains(Borders::BOTTOM) {
-            inner.height = inner.height.saturating_sub(1);
+            outer.height = outer.height.saturating_add(1);
+        }
+        outer
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        if !area.contains(position) {
+            return None;
+        }
+
+        let mut sides = Borders::NONE;
+        sides.set(Borders::LEFT, position.x == area.left());
+        sides.set(Borders::TOP, position.y == area.top());
+        sides.set(Borders::RIGHT, position.x == area.right().saturating_sub(1));
+        sides.set(
+            Borders::BOTTOM,
+            position.y == area.bottom().saturating_sub(1),
+        );
+        let sides = sides & self.get_borders();
+
+        (!sides.is_empty()).then_some(HitPart::Border(sides))
+    }
+}
+
+/// Draws all sides and corners of `border`.
+fn draw_border<B: Border + ?Sized>(border: &B, area: Rect, buffer: &mut Buffer) {
+    let borders = border.get_borders();
+    let border_set = border.get_border_set();
+
+    if borders.contains(Borders::LEFT) {
+        border.render_left(area, buffer, border_set.left);
+    }
+    if borders.contains(Borders::TOP) {
+        border.render_top(area, buffer, border_set.top);
+    }
+    if borders.contains(Borders::RIGHT) {
+        border.render_right(area, buffer, border_set.right);
+    }
+    if borders.contains(Borders::BOTTOM) {
+        border.render_bottom(area, buffer, border_set.bottom);
+    }
+
+    border.render_corners(area, buffer, &border_set);
+}
+
+/// Draws `border`, merging it with the existing content of `buffer` using `strategy`.
+///
+/// The border is first drawn on a scratch buffer so its own sides and corners
+/// don't merge with each other; only the result is merged into `buffer`.
+fn render_border<B: Border + ?Sized>(
+    border: &B,
+    area: Rect,
+    buffer: &mut Buffer,
+    strategy: MergeStrategy,
+) {
+    if strategy == MergeStrategy::Replace {
+        draw_border(border, area, buffer);
+    } else {
+        merge_border(border, area, buffer, strategy);
+    }
+    style_border(border, area, buffer);
+}
+
+/// Draws `border` on a scratch buffer and merges the result into `buffer`.
+fn merge_border<B: Border + ?Sized>(
+    border: &B,
+    area: Rect,
+    buffer: &mut Buffer,
+    strategy: MergeStrategy,
+) {
+    let mut scratch = Buffer::empty(area);
+    draw_border(border, area, &mut scratch);
+
+    let borders = border.get_borders();
+    let (right, bottom) = (
+        area.right().saturating_sub(1),
+        area.bottom().saturating_sub(1),
+    );
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let drawn = (borders.contains(Borders::LEFT) && x == area.left())
+                || (borders.contains(Borders::RIGHT) && x == right)
+                || (borders.contains(Borders::TOP) && y == area.top())
+                || (borders.contains(Borders::BOTTOM) && y == bottom);
+            if !drawn {
+                continue;
+            }
+            let Some(new) = single_char(scratch[(x, y)].symbol()) else {
+                continue;
+            };
+            let merged = single_char(buffer[(x, y)].symbol())
+                .map_or(new, |existing| strategy.merge(existing, new));
+            buffer[(x, y)].set_char(merged);
+        }
+    }
+}
+
+/// Applies the [`BorderStyles`] of `border` to its sides and corners.
+fn style_border<B: Border + ?Sized>(border: &B, area: Rect, buffer: &mut Buffer) {
+    let styles = border.get_border_styles();
+    if styles == BorderStyles::default() || area.is_empty() {
+        return;
+    }
+
+    let borders = border.get_borders();
+    let (right, bottom) = (area.right() - 1, area.bottom() - 1);
+    let sides = [
+        (Borders::LEFT, styles.left, Rect { width: 1, ..area }),
+        (Borders::TOP, styles.top, Rect { height: 1, ..area }),
+        (
+            Borders::RIGHT,
+            styles.right,
+            Rect {
+                x: right,
+                width: 1,
+                ..area
+            },
+        ),
+        (
+            Borders::BOTTOM,
+            styles.bottom,
+            Rect {
+                y: bottom,
+                height: 1,
+                ..area
+            },
+        ),
+    ];
+    for (side, style, side_area) in sides {
+        if let Some(style) = style.filter(|_| borders.contains(side)) {
+            buffer.set_style(side_area, style);
+        }
+    }
+
+    let corners = [
+        (
+            Borders::LEFT | Borders::TOP,
+            styles.top_left,
+            (area.left(), area.top()),
+        ),
+        (
+            Borders::RIGHT | Borders::TOP,
+            styles.top_right,
+            (right, area.top()),
+        ),
+        (
+            Borders::LEFT | Borders::BOTTOM,
+            styles.bottom_left,
+            (area.left(), bottom),
+        ),
+        (
+            Borders::RIGHT | Borders::BOTTOM,
+            styles.bottom_right,
+            (right, bottom),
+        ),
+    ];
+    for (sides, style, position) in corners {
+        if let Some(style) = style.filter(|_| borders.contains(sides)) {
+            buffer[position].set_style(style);
+        }
+    }
+}
+
+/// Returns the character if `symbol` consists of exactly one.
+fn single_char(symbol: &str) -> Option<char> {
+    let mut chars = symbol.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+// ===== Merging =====
+
+/// Determines how a border combines with box-drawing characters already in the buffer.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_garnish::border::MergeStrategy;
+///
+/// assert_eq!(MergeStrategy::Exact.merge('┐', '┌'), '┬');
+/// assert_eq!(MergeStrategy::Exact.merge('│', '━'), '┿');
+/// assert_eq!(MergeStrategy::Exact.merge('║', '━'), '━'); // no such glyph
+/// assert_eq!(MergeStrategy::Fuzzy.merge('║', '━'), '╋');
+/// assert_eq!(MergeStrategy::Replace.merge('┐', '┌'), '┌');
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MergeStrategy {
+    /// Overwrites existing characters, the default.
+    #[default]
+    Replace,
+    /// Merges into a junction when a matching glyph exists, otherwise replaces.
+    Exact,
+    /// Like `Exact`, but approximates combinations without a glyph (e.g. double
+    /// and thick lines) using the line type of the new character.
+    Fuzzy,
+}
+
+impl MergeStrategy {
+    /// Combines the `new` character with an `existing` one.
+    ///
+    /// Characters that are not box-drawing lines are never merged; `new` is
+    /// returned instead.
+    #[must_use]
+    pub fn merge(self, existing: char, new: char) -> char {
+        if self == Self::Replace {
+            return new;
+        }
+        let (Some(existing_arms), Some(new_arms)) = (arms(existing), arms(new)) else {
+            return new;
+        };
+
+        let mut merged = existing_arms;
+        for (arm, new_arm) in merged.iter_mut().zip(new_arms) {
+            if new_arm != LineKind::None {
+                *arm = new_arm;
+            }
+        }
+
+        if merged == new_arms {
+            return new;
+        }
+        if merged == existing_arms {
+            return existing;
+        }
+        if let Some(symbol) = glyph(merged) {
+            return symbol;
+        }
+        if self == Self::Fuzzy {
+            let kind = new_arms
+                .into_iter()
+                .find(|arm| *arm != LineKind::None)
+                .unwrap_or(LineKind::Light);
+            for kind in [kind, LineKind::Light] {
+                let uniform = merged.map(|arm| if arm == LineKind::None { arm } else { kind });
+                if let Some(symbol) = glyph(uniform) {
+                    return symbol;
+                }
+            }
+        }
+        new
+    }
+}
+
+/// The type of line of one arm of a box-drawing character.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum LineKind {
+    None,
+    Light,
+    Heavy,
+    Double,
+}
+
+/// Arms of a box-drawing character in the order up, right, down, left.
+type Arms = [LineKind; 4];
+
+/// Returns the arms of a box-drawing character.
+fn arms(symbol: char) -> Option<Arms> {
+    BOX_DRAWING
+        .iter()
+        .chain(BOX_DRAWING_VARIANTS)
+        .find(|(c, _)| *c == symbol)
+        .map(|(_, arms)| *arms)
+}
+
+/// Returns the box-drawing character with the given arms.
+fn glyph(arms: Arms) -> Option<char> {
+    BOX_DRAWING
+        .iter()
+        .find(|(_, a)| *a == arms)
+        .map(|(c, _)| *c)
+}
+
+const N: LineKind = LineKind::None;
+const L: LineKind = LineKind::Light;
+const H: LineKind = LineKind::Heavy;
+const D: LineKind = LineKind::Double;
+
+/// Box-drawing characters and their arms (up, right, down, left).
+#[rustfmt::skip]
+const BOX_DRAWING: &[(char, Arms)] = &[
+    ('─', [N, L, N, L]), ('━', [N, H, N, H]), ('│', [L, N, L, N]), ('┃', [H, N, H, N]),
+    ('┌', [N, L, L, N]), ('┍', [N, H, L, N]), ('┎', [N, L, H, N]), ('┏', [N, H, H, N]),
+    ('┐', [N, N, L, L]), ('┑', [N, N, L, H]), ('┒', [N, N, H, L]), ('┓', [N, N, H, H]),
+    ('└', [L, L, N, N]), ('┕', [L, H, N, N]), ('┖', [H, L, N, N]), ('┗', [H, H, N, N]),
+    ('┘', [L, N, N, L]), ('┙', [L, N, N, H]), ('┚', [H, N, N, L]), ('┛', [H, N, N, H]),
+    ('├', [L, L, L, N]), ('┝', [L, H, L, N]), ('┞', [H, L, L, N]), ('┟', [L, L, H, N]),
+    ('┠', [H, L, H, N]), ('┡', [H, H, L, N]), ('┢', [L, H, H, N]), ('┣', [H, H, H, N]),
+    ('┤', [L, N, L, L]), ('┥', [L, N, L, H]), ('┦', [H, N, L, L]), ('┧', [L, N, H, L]),
+    ('┨', [H, N, H, L]), ('┩', [H, N, L, H]), ('┪', [L, N, H, H]), ('┫', [H, N, H, H]),
+    ('┬', [N, L, L, L]), ('┭', [N, L, L, H]), ('┮', [N, H, L, L]), ('┯', [N, H, L, H]),
+    ('┰', [N, L, H, L]), ('┱', [N, L, H, H]), ('┲', [N, H, H, L]), ('┳', [N, H, H, H]),
+    ('┴', [L, L, N, L]), ('┵', [L, L, N, H]), ('┶', [L, H, N, L]), ('┷', [L, H, N, H]),
+    ('┸', [H, L, N, L]), ('┹', [H, L, N, H]), ('┺', [H, H, N, L]), ('┻', [H, H, N, H]),
+    ('┼', [L, L, L, L]), ('┽', [L, L, L, H]), ('┾', [L, H, L, L]), ('┿', [L, H, L, H]),
+    ('╀', [H, L, L, L]), ('╁', [L, L, H, L]), ('╂', [H, L, H, L]), ('╃', [H, L, L, H]),
+    ('╄', [H, H, L, L]), ('╅', [L, L, H, H]), ('╆', [L, H, H, L]), ('╇', [H, H, L, H]),
+    ('╈', [L, H, H, H]), ('╉', [H, L, H, H]), ('╊', [H, H, H, L]), ('╋', [H, H, H, H]),
+    ('╴', [N, N, N, L]), ('╵', [L, N, N, N]), ('╶', [N, L, N, N]), ('╷', [N, N, L, N]),
+    ('╸', [N, N, N, H]), ('╹', [H, N, N, N]), ('╺', [N, H, N, N]), ('╻', [N, N, H, N]),
+    ('╼', [N, H, N, L]), ('╽', [L, N, H, N]), ('╾', [N, L, N, H]), ('╿', [H, N, L, N]),
+    ('═', [N, D, N, D]), ('║', [D, N, D, N]),
+    ('╒', [N, D, L, N]), ('╓', [N, L, D, N]), ('╔', [N, D, D, N]),
+    ('╕', [N, N, L, D]), ('╖', [N, N, D, L]), ('╗', [N, N, D, D]),
+    ('╘', [L, D, N, N]), ('╙', [D, L, N, N]), ('╚', [D, D, N, N]),
+    ('╛', [L, N, N, D]), ('╜', [D, N, N, L]), ('╝', [D, N, N, D]),
+    ('╞', [L, D, L, N]), ('╟', [D, L, D, N]), ('╠', [D, D, D, N]),
+    ('╡', [L, N, L, D]), ('╢', [D, N, D, L]), ('╣', [D, N, D, D]),
+    ('╤', [N, D, L, D]), ('╥', [N, L, D, L]), ('╦', [N, D, D, D]),
+    ('╧', [L, D, N, D]), ('╨', [D, L, N, L]), ('╩', [D, D, N, D]),
+    ('╪', [L, D, L, D]), ('╫', [D, L, D, L]), ('╬', [D, D, D, D]),
+];
+
+/// Rounded and dashed characters; they are recognised when merging, but
+/// junctions always use the plain characters from [`BOX_DRAWING`].
+#[rustfmt::skip]
+const BOX_DRAWING_VARIANTS: &[(char, Arms)] = &[
+    ('╭', [N, L, L, N]), ('╮', [N, N, L, L]), ('╯', [L, N, N, L]), ('╰', [L, L, N, N]),
+    ('╌', [N, L, N, L]), ('┄', [N, L, N, L]), ('┈', [N, L, N, L]),
+    ('╍', [N, H, N, H]), ('┅', [N, H, N, H]), ('┉', [N, H, N, H]),
+    ('╎', [L, N, L, N]), ('┆', [L, N, L, N]), ('┊', [L, N, L, N]),
+    ('╏', [H, N, H, N]), ('┇', [H, N, H, N]), ('┋', [H, N, H, N]),
+];
+
+// ===== Collapsed Borders =====
+
+/// Renders a grid of cells whose neighbours share a single border line.
+///
+/// Use [`split`](BorderGrid::split) instead of `Layout::split` to get the outer
+/// area of every cell: neighbouring cells overlap by one column or row. Nest
+/// splits to build a grid. [`render`](BorderGrid::render) draws the borders of
+/// all cells, merging them into junctions, and returns the inner areas in
+/// which to render the widgets of the cells. Or render garnished widgets with
+/// [`render_widgets`](BorderGrid::render_widgets), which merges their own
+/// borders. Don't use `Layout` spacing, cells must be adjacent to share a
+/// border.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::{Constraint, Layout, Rect}};
+/// use ratatui_garnish::border::{BorderGrid, PlainBorder};
+///
+/// let area = Rect::new(0, 0, 9, 5);
+/// let mut buffer = Buffer::empty(area);
+///
+/// let grid = BorderGrid::new(PlainBorder::default());
+/// let rows = Layout::vertical([Constraint::Fill(1); 2]);
+/// let columns = Layout::horizontal([Constraint::Fill(1); 2]);
+/// let cells: Vec<Rect> = grid
+///     .split(area, &rows)
+///     .into_iter()
+///     .flat_map(|row| grid.split(row, &columns))
+///     .collect();
+///
+/// let inner = grid.render(&cells, &mut buffer);
+/// assert_eq!(inner[0], Rect::new(1, 1, 3, 1));
+/// assert_eq!(buffer, Buffer::with_lines([
+///     "┌───┬───┐",
+///     "│   │   │",
+///     "├───┼───┤",
+///     "│   │   │",
+///     "└───┴───┘",
+/// ]));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct BorderGrid<B> {
+    border: B,
+    merge: MergeStrategy,
+}
+
+impl<B: Border> BorderGrid<B> {
+    /// Creates a grid drawing `border` around every cell.
+    ///
+    /// By default, borders are merged using `MergeStrategy::Exact`.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn new(border: B) -> Self {
+        Self {
+            border,
+            merge: MergeStrategy::Exact,
+        }
+    }
+
+    /// Sets the strategy used to merge the borders of neighbouring cells.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn merge(mut self, merge: MergeStrategy) -> Self {
+        self.merge = merge;
+        self
+    }
+
+    /// Splits `area` using `layout` into the outer areas of cells.
+    ///
+    /// Every cell is one column wider and one row taller than the part of
+    /// the layout it occupies, so it overlaps its right and bottom neighbour.
+    #[must_use]
+    pub fn split(&self, area: Rect, layout: &Layout) -> Vec<Rect> {
+        let shrunk = Rect {
+            width: area.width.saturating_sub(1),
+            height: area.height.saturating_sub(1),
+            ..area
+        };
+        layout
+            .split(shrunk)
+            .iter()
+            .map(|cell| Rect {
+                width: cell.width.saturating_add(1),
+                height: cell.height.saturating_add(1),
+                ..*cell
+            })
+            .collect()
+    }
+
+    /// Renders the border of every cell and returns the inner areas of the cells.
+    ///
+    /// The inner areas are the cell areas modified by the border, in the
+    /// same order as `cells`.
+    pub fn render(&self, cells: &[Rect], buffer: &mut Buffer) -> Vec<Rect> {
+        cells
+            .iter()
+            .map(|cell| {
+                render_border(&self.border, *cell, buffer, self.merge);
+                crate::RenderModifier::modify_area(&self.border, *cell)
+            })
+            .collect()
+    }
+
+    /// Renders garnished widgets into `cells`, in the same order.
+    ///
+    /// The border garnishes of the widgets are merged using the strategy of
+    /// the grid, so the borders of neighbouring widgets collapse into a
+    /// single line. The border of the grid itself isn't drawn.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::{Constraint, Layout, Rect}, text::Text};
+    /// use ratatui_garnish::{GarnishableWidget, border::{BorderGrid, PlainBorder}};
+    ///
+    /// let area = Rect::new(0, 0, 9, 3);
+    /// let mut buffer = Buffer::empty(area);
+    ///
+    /// let grid = BorderGrid::new(PlainBorder::default());
+    /// let cells = grid.split(area, &Layout::horizontal([Constraint::Fill(1); 2]));
+    /// let widgets = ["ab", "cd"].map(|text| Text::raw(text).garnish(PlainBorder::default()));
+    ///
+    /// grid.render_widgets(&cells, widgets, &mut buffer);
+    /// assert_eq!(buffer, Buffer::with_lines([
+    ///     "┌───┬───┐",
+    ///     "│ab │cd │",
+    ///     "└───┴───┘",
+    /// ]));
+    /// ```
+    pub fn render_widgets<'a, W, I>(&self, cells: &[Rect], widgets: I, buffer: &mut Buffer)
+    where
+        W: Widget,
+        I: IntoIterator<Item = GarnishedWidget<'a, W>>,
+    {
+        for (cell, mut widget) in cells.iter().zip(widgets) {
+            for garnish in &mut widget.garnishes {
+                if let Some(border) = custom_border(garnish) {
+                    *garnish = border.merge(self.merge).into();
+                }
+            }
+            widget.render(*cell, buffer);
         }
-        inner
     }
 }
 
+/// Converts a border garnish into a [`CustomBorder`], `None` for other garnishes.
+fn custom_border(garnish: &Garnish<'_>) -> Option<CustomBorder> {
+    Some(match garnish {
+        Garnish::CharBorder(border) => {
+            CustomBorder::new(BorderSet::new(border.symbol)).borders(border.borders)
+        }
+        Garnish::CustomBorder(border) => *border,
+        Garnish::DashedBorder(border) => (*border).into(),
+        Garnish::DoubleBorder(border) => (*border).into(),
+        Garnish::FatInsideBorder(border) => (*border).into(),
+        Garnish::FatOutsideBorder(border) => (*border).into(),
+        Garnish::PlainBorder(border) => (*border).into(),
+        Garnish::QuadrantInsideBorder(border) => (*border).into(),
+        Garnish::QuadrantOutsideBorder(border) => (*border).into(),
+        Garnish::RoundedBorder(border) => (*border).into(),
+        Garnish::RoundedDashedBorder(border) => (*border).into(),
+        Garnish::ThickBorder(border) => (*border).into(),
+        Garnish::ThickDashedBorder(border) => (*border).into(),
+        _ => return None,
+    })
+}
+
 // ===== Character Sets =====
 
 /// Character set for rendering borders with different visual styles.
@@ -519,6 +1082,179 @@ impl BorderSet {
         self.right = symbol;
         self
     }
+
+    /// Returns the characters that end the line of `side` around a label.
+    ///
+    /// The caps are the junctions turning the side into a crossing line,
+    /// e.g. `┤` and `├` for a plain top side, or `┴` and `┬` for a plain
+    /// left side. For sides that aren't box-drawing lines both caps are
+    /// the side character. `side` should be a single side; combinations
+    /// use the first of top, bottom, left and right.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_garnish::border::{Borders, BorderSet};
+    ///
+    /// assert_eq!(BorderSet::plain().caps(Borders::TOP), ['┤', '├']);
+    /// assert_eq!(BorderSet::double().caps(Borders::RIGHT), ['╩', '╦']);
+    /// assert_eq!(BorderSet::new('*').caps(Borders::TOP), ['*', '*']);
+    /// ```
+    #[must_use]
+    pub fn caps(&self, side: Borders) -> [char; 2] {
+        let (symbol, vertical) = if side.contains(Borders::TOP) {
+            (self.top, false)
+        } else if side.contains(Borders::BOTTOM) {
+            (self.bottom, false)
+        } else if side.contains(Borders::LEFT) {
+            (self.left, true)
+        } else {
+            (self.right, true)
+        };
+        let Some(kind) = arms(symbol).map(|[up, right, ..]| if vertical { up } else { right })
+        else {
+            return [symbol; 2];
+        };
+        // the line continues on the outer side of each cap
+        let caps = if vertical {
+            [[kind, kind, N, kind], [N, kind, kind, kind]]
+        } else {
+            [[kind, N, kind, kind], [kind, kind, kind, N]]
+        };
+        caps.map(|arms| glyph(arms).unwrap_or(symbol))
+    }
+}
+
+// ===== Styles =====
+
+/// Styles for the sides and corners of a border.
+///
+/// Unlike a `Style` garnish, which styles the whole area, these styles only
+/// touch the border cells. Sides and corners without a style inherit the
+/// style of the area. Corner styles take precedence over side styles.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use ratatui_garnish::border::BorderStyles;
+///
+/// let grey = Style::default().fg(Color::Gray);
+/// let red = Style::default().fg(Color::Red);
+///
+/// // A grey frame with red corners
+/// let styles = BorderStyles::new(grey).corners(red);
+///
+/// // Only the top edge is styled
+/// let top = BorderStyles { top: Some(red), ..Default::default() };
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct BorderStyles {
+    /// Style of the corner where the top and left sides meet, drawn when
+    /// both sides are.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_option_style")
+    )]
+    pub top_left: Option<Style>,
+    /// Style of the corner where the top and right sides meet, drawn when
+    /// both sides are.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_option_style")
+    )]
+    pub top_right: Option<Style>,
+    /// Style of the corner where the bottom and left sides meet, drawn
+    /// when both sides are.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_option_style")
+    )]
+    pub bottom_left: Option<Style>,
+    /// Style of the corner where the bottom and right sides meet, drawn
+    /// when both sides are.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_option_style")
+    )]
+    pub bottom_right: Option<Style>,
+    /// Style of the left side, from the top to the bottom row.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_option_style")
+    )]
+    pub left: Option<Style>,
+    /// Style of the right side, from the top to the bottom row.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_option_style")
+    )]
+    pub right: Option<Style>,
+    /// Style of the top side, from the left to the right column. Drawn
+    /// over the left and right sides where they meet.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_option_style")
+    )]
+    pub top: Option<Style>,
+    /// Style of the bottom side, from the left to the right column. Drawn
+    /// over the left and right sides where they meet.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_option_style")
+    )]
+    pub bottom: Option<Style>,
+}
+
+impl BorderStyles {
+    /// Creates new `BorderStyles` with all sides and corners set to `style`.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn new(style: Style) -> Self {
+        Self {
+            top_left: Some(style),
+            top_right: Some(style),
+            bottom_left: Some(style),
+            bottom_right: Some(style),
+            left: Some(style),
+            right: Some(style),
+            top: Some(style),
+            bottom: Some(style),
+        }
+    }
+
+    /// Sets the style of all corners.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn corners(mut self, style: Style) -> Self {
+        self.top_left = Some(style);
+        self.top_right = Some(style);
+        self.bottom_left = Some(style);
+        self.bottom_right = Some(style);
+        self
+    }
+
+    /// Sets the style of the left and right sides.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn verticals(mut self, style: Style) -> Self {
+        self.left = Some(style);
+        self.right = Some(style);
+        self
+    }
+
+    /// Sets the style of the top and bottom sides.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn horizontals(mut self, style: Style) -> Self {
+        self.top = Some(style);
+        self.bottom = Some(style);
+        self
+    }
+
+    /// Sets the style of all sides, but not the corners.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn sides(self, style: Style) -> Self {
+        self.verticals(style).horizontals(style)
+    }
 }
 
 // ===== Border Implementations =====
@@ -561,6 +1297,20 @@ macro_rules! standard_border {
             }
         }
 
+        impl $name {
+            /// Converts the border into a [`CustomBorder`] using the given [`MergeStrategy`].
+            #[must_use = "method returns a new instance and does not mutate the original"]
+            pub fn merge(self, merge: MergeStrategy) -> CustomBorder {
+                CustomBorder::from(self).merge(merge)
+            }
+
+            /// Converts the border into a [`CustomBorder`] using the given [`BorderStyles`].
+            #[must_use = "method returns a new instance and does not mutate the original"]
+            pub fn styles(self, styles: BorderStyles) -> CustomBorder {
+                CustomBorder::from(self).styles(styles)
+            }
+        }
+
         impl Border for $name {
             fn get_borders(&self) -> Borders {
                 self.0
@@ -570,6 +1320,12 @@ macro_rules! standard_border {
                 BorderSet::$border_set_fn()
             }
         }
+
+        impl From<$name> for CustomBorder {
+            fn from(value: $name) -> Self {
+                Self::new(BorderSet::$border_set_fn()).borders(value.0)
+            }
+        }
     };
 }
 
@@ -697,6 +1453,10 @@ pub struct CustomBorder {
     pub char_set: BorderSet,
     /// Which borders to draw, defaults to `Borders::ALL`.
     pub borders: Borders,
+    /// How to combine with existing borders, defaults to `MergeStrategy::Replace`.
+    pub merge: MergeStrategy,
+    /// Styles of the sides and corners, defaults to none.
+    pub styles: BorderStyles,
 }
 
 impl Default for CustomBorder {
@@ -705,6 +1465,8 @@ impl Default for CustomBorder {
         Self {
             char_set: BorderSet::plain(),
             borders: Borders::ALL,
+            merge: MergeStrategy::Replace,
+            styles: BorderStyles::default(),
         }
     }
 }
@@ -727,6 +1489,38 @@ impl CustomBorder {
         self.borders = borders;
         self
     }
+
+    /// Sets how the border combines with existing borders in the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_garnish::border::{BorderSet, CustomBorder, MergeStrategy};
+    ///
+    /// let border = CustomBorder::new(BorderSet::double()).merge(MergeStrategy::Fuzzy);
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn merge(mut self, merge: MergeStrategy) -> Self {
+        self.merge = merge;
+        self
+    }
+
+    /// Sets the styles of the sides and corners.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use ratatui_garnish::border::{BorderSet, BorderStyles, CustomBorder};
+    ///
+    /// let border = CustomBorder::new(BorderSet::rounded())
+    ///     .styles(BorderStyles::new(Style::default().fg(Color::Blue)));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn styles(mut self, styles: BorderStyles) -> Self {
+        self.styles = styles;
+        self
+    }
 }
 
 impl Border for CustomBorder {
@@ -737,6 +1531,14 @@ impl Border for CustomBorder {
     fn get_border_set(&self) -> BorderSet {
         self.char_set
     }
+
+    fn merge_strategy(&self) -> MergeStrategy {
+        self.merge
+    }
+
+    fn get_border_styles(&self) -> BorderStyles {
+        self.styles
+    }
 }
 
 bitflags::bitflags! {
@@ -890,6 +1692,20 @@ mod tests {
         assert_eq!(inner_area.height, 9); // Only top border reduces height
     }
 
+    #[test]
+    fn area_expansion_inverts_modification() {
+        let area = Rect::new(1, 1, 8, 8);
+
+        assert_eq!(
+            PlainBorder::default().expand_area(area),
+            Rect::new(0, 0, 10, 10)
+        );
+        assert_eq!(
+            PlainBorder::new(Borders::RIGHT | Borders::BOTTOM).expand_area(area),
+            Rect::new(1, 1, 9, 9)
+        );
+    }
+
     #[test]
     fn border_deref_provides_border_access() {
         let mut border = PlainBorder::new(Borders::TOP);
@@ -939,6 +1755,154 @@ mod tests {
         assert_eq!(inner_area.height, 0);
     }
 
+    #[test]
+    fn merge_strategy_combines_lines() {
+        assert_eq!(MergeStrategy::Exact.merge('─', '│'), '┼');
+        assert_eq!(MergeStrategy::Exact.merge('┘', '└'), '┴');
+        assert_eq!(MergeStrategy::Exact.merge('┐', '┘'), '┤');
+        assert_eq!(MergeStrategy::Exact.merge('┃', '─'), '╂');
+        assert_eq!(MergeStrategy::Exact.merge('│', '═'), '╪');
+        assert_eq!(MergeStrategy::Exact.merge('╮', '╭'), '┬');
+        assert_eq!(MergeStrategy::Exact.merge('╌', '│'), '┼');
+        assert_eq!(MergeStrategy::Exact.merge('─', '╌'), '╌');
+        assert_eq!(MergeStrategy::Exact.merge('a', '│'), '│');
+        assert_eq!(MergeStrategy::Exact.merge('│', '*'), '*');
+        assert_eq!(MergeStrategy::Exact.merge('═', '┃'), '┃');
+        assert_eq!(MergeStrategy::Fuzzy.merge('═', '┃'), '╋');
+        assert_eq!(MergeStrategy::Replace.merge('─', '│'), '│');
+    }
+
+    #[test]
+    fn merged_border_does_not_merge_with_itself() {
+        let mut buffer = create_test_buffer(5, 5);
+        let area = Rect::new(0, 0, 5, 5);
+        let expected = {
+            let mut buffer = create_test_buffer(5, 5);
+            PlainBorder::default().before_render(area, &mut buffer);
+            buffer
+        };
+
+        PlainBorder::default()
+            .merge(MergeStrategy::Exact)
+            .before_render(area, &mut buffer);
+
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn adjacent_merged_borders_share_junctions() {
+        let mut buffer = create_test_buffer(9, 5);
+        let border = PlainBorder::default().merge(MergeStrategy::Exact);
+
+        border.before_render(Rect::new(0, 0, 5, 3), &mut buffer);
+        border.before_render(Rect::new(4, 0, 5, 3), &mut buffer);
+        border.before_render(Rect::new(0, 2, 9, 3), &mut buffer);
+
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "┌───┬───┐",
+                "│   │   │",
+                "├───┴───┤",
+                "│       │",
+                "└───────┘",
+            ])
+        );
+    }
+
+    #[test]
+    fn standard_border_converts_to_custom_border() {
+        let border = ThickBorder::new(Borders::TOP).merge(MergeStrategy::Fuzzy);
+
+        assert_eq!(border.char_set, BorderSet::thick());
+        assert_eq!(border.borders, Borders::TOP);
+        assert_eq!(border.merge_strategy(), MergeStrategy::Fuzzy);
+    }
+
+    #[test]
+    fn border_styles_only_touch_border_cells() {
+        use ratatui::style::{Color, Style};
+
+        let grey = Style::default().fg(Color::Gray);
+        let red = Style::default().fg(Color::Red);
+        let border = PlainBorder::new(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+            .merge(MergeStrategy::Exact)
+            .styles(BorderStyles {
+                top: Some(red),
+                bottom: Some(red),
+                bottom_right: Some(red),
+                ..BorderStyles::new(grey)
+            });
+
+        let mut buffer = create_test_buffer(4, 3);
+        border.before_render(buffer.area, &mut buffer);
+
+        let fg = |x, y| buffer[(x, y)].fg;
+        assert_eq!(
+            (fg(0, 0), fg(1, 0), fg(3, 0)),
+            (Color::Gray, Color::Red, Color::Gray)
+        );
+        assert_eq!((fg(0, 1), fg(3, 2)), (Color::Gray, Color::Gray));
+        assert_eq!((fg(1, 1), fg(1, 2)), (Color::Reset, Color::Reset));
+    }
+
+    #[test]
+    fn border_grid_collapses_borders() {
+        use ratatui::layout::Constraint;
+
+        let area = Rect::new(0, 0, 10, 4);
+        let mut buffer = create_test_buffer(10, 4);
+        let grid = BorderGrid::new(DoubleBorder::default()).merge(MergeStrategy::Fuzzy);
+
+        let cells = grid.split(area, &Layout::horizontal([Constraint::Length(3); 3]));
+        assert_eq!(
+            cells,
+            vec![
+                Rect::new(0, 0, 4, 4),
+                Rect::new(3, 0, 4, 4),
+                Rect::new(6, 0, 4, 4),
+            ]
+        );
+
+        let inner = grid.render(&cells, &mut buffer);
+        assert_eq!(inner[1], Rect::new(4, 1, 2, 2));
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["╔══╦══╦══╗", "║  ║  ║  ║", "║  ║  ║  ║", "╚══╩══╩══╝",])
+        );
+    }
+
+    #[test]
+    fn border_grid_collapses_borders_of_widgets() {
+        use crate::GarnishableWidget;
+        use ratatui::{layout::Constraint, text::Text};
+
+        let area = Rect::new(0, 0, 9, 5);
+        let mut buffer = create_test_buffer(9, 5);
+        let grid = BorderGrid::new(PlainBorder::default());
+
+        for (row, texts) in grid
+            .split(area, &Layout::vertical([Constraint::Fill(1); 2]))
+            .into_iter()
+            .zip([["a", "b"], ["c", "d"]])
+        {
+            let cells = grid.split(row, &Layout::horizontal([Constraint::Fill(1); 2]));
+            let widgets = texts.map(|text| Text::raw(text).garnish(PlainBorder::default()));
+            grid.render_widgets(&cells, widgets, &mut buffer);
+        }
+
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "┌───┬───┐",
+                "│a  │b  │",
+                "├───┼───┤",
+                "│c  │d  │",
+                "└───┴───┘",
+            ])
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn plain_border_serialization() {
@@ -969,4 +1933,27 @@ mod tests {
         let restored: CustomBorder = serde_json::from_str(&json).unwrap();
         assert_eq!(restored, border);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn border_styles_serialization() {
+        let red = ratatui::style::Style::new().fg(ratatui::style::Color::Red);
+        let border = CustomBorder::new(BorderSet::rounded())
+            .merge(MergeStrategy::Exact)
+            .styles(
+                BorderStyles::default()
+                    .horizontals(red)
+                    .corners(red.add_modifier(ratatui::style::Modifier::BOLD)),
+            );
+        let json = serde_json::to_string(&border).unwrap();
+        assert!(json.contains(r#""merge":"Exact""#));
+
+        let restored: CustomBorder = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, border);
+
+        // missing styles and merge strategy take their defaults
+        let restored: CustomBorder = serde_json::from_str(r#"{ "borders": "TOP" }"#).unwrap();
+        assert_eq!(restored.merge, MergeStrategy::Replace);
+        assert_eq!(restored.styles, BorderStyles::default());
+    }
 }
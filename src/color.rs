@@ -0,0 +1,184 @@
+//! Color conversion and interpolation used by garnishes that compute colors.
+
+use ratatui::style::Color;
+
+/// RGB values of the 16 ANSI colors, as used by xterm.
+const ANSI: [(Color, [u8; 3]); 16] = [
+    (Color::Black, [0, 0, 0]),
+    (Color::Red, [128, 0, 0]),
+    (Color::Green, [0, 128, 0]),
+    (Color::Yellow, [128, 128, 0]),
+    (Color::Blue, [0, 0, 128]),
+    (Color::Magenta, [128, 0, 128]),
+    (Color::Cyan, [0, 128, 128]),
+    (Color::Gray, [192, 192, 192]),
+    (Color::DarkGray, [128, 128, 128]),
+    (Color::LightRed, [255, 0, 0]),
+    (Color::LightGreen, [0, 255, 0]),
+    (Color::LightYellow, [255, 255, 0]),
+    (Color::LightBlue, [0, 0, 255]),
+    (Color::LightMagenta, [255, 0, 255]),
+    (Color::LightCyan, [0, 255, 255]),
+    (Color::White, [255, 255, 255]),
+];
+
+/// Intensities of the 6x6x6 color cube of the 256 color palette.
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The number of colors a terminal supports.
+///
+/// Colors computed by garnishes, e.g. the steps of a gradient, are
+/// quantized to the nearest color of the palette.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ColorDepth {
+    /// 24-bit RGB colors, the default.
+    #[default]
+    TrueColor,
+    /// The 256 color palette.
+    Indexed,
+    /// The 16 ANSI colors.
+    Ansi,
+}
+
+impl ColorDepth {
+    /// Returns the color of the palette nearest to `rgb`.
+    pub(crate) fn quantize(self, [r, g, b]: [u8; 3]) -> Color {
+        match self {
+            Self::TrueColor => Color::Rgb(r, g, b),
+            Self::Indexed => Color::Indexed(nearest_indexed([r, g, b])),
+            Self::Ansi => nearest_ansi([r, g, b]),
+        }
+    }
+}
+
+/// Returns the RGB values of `color`, `None` for `Color::Reset`.
+pub(crate) fn rgb(color: Color) -> Option<[u8; 3]> {
+    match color {
+        Color::Reset => None,
+        Color::Rgb(r, g, b) => Some([r, g, b]),
+        Color::Indexed(index) => Some(indexed_rgb(index)),
+        named => ANSI
+            .iter()
+            .find(|(ansi, _)| *ansi == named)
+            .map(|(_, rgb)| *rgb),
+    }
+}
+
+/// Interpolates between `from` and `to`, `t` ranges from 0.0 to 1.0.
+pub(crate) fn lerp(from: [u8; 3], to: [u8; 3], t: f32) -> [u8; 3] {
+    let t = t.clamp(0.0, 1.0);
+    std::array::from_fn(|i| {
+        let (from, to) = (f32::from(from[i]), f32::from(to[i]));
+        // clamped to 0.0..=255.0, the cast can't truncate
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let value = (to - from).mul_add(t, from).round().clamp(0.0, 255.0) as u8;
+        value
+    })
+}
+
+/// Interpolates between the colors `from` and `to` with `depth` colors.
+///
+/// `Color::Reset` has no RGB value, when either color is `Reset` the
+/// result switches from `from` to `to` halfway.
+pub(crate) fn interpolate(from: Color, to: Color, t: f32, depth: ColorDepth) -> Color {
+    match (rgb(from), rgb(to)) {
+        (Some(from), Some(to)) => depth.quantize(lerp(from, to, t)),
+        _ if t < 0.5 => from,
+        _ => to,
+    }
+}
+
+/// Returns the RGB values of a color of the 256 color palette.
+fn indexed_rgb(index: u8) -> [u8; 3] {
+    match index {
+        0..16 => ANSI[usize::from(index)].1,
+        16..232 => {
+            let index = index - 16;
+            [
+                CUBE[usize::from(index / 36)],
+                CUBE[usize::from(index / 6 % 6)],
+                CUBE[usize::from(index % 6)],
+            ]
+        }
+        _ => [8 + (index - 232) * 10; 3],
+    }
+}
+
+/// Returns the index of the color of the 256 color palette nearest to `rgb`.
+///
+/// Only the color cube and the grayscale ramp are considered, the first 16
+/// colors are often redefined by terminal themes.
+fn nearest_indexed(rgb: [u8; 3]) -> u8 {
+    let level = |value: u8| {
+        (0..6u8)
+            .min_by_key(|&i| CUBE[usize::from(i)].abs_diff(value))
+            .unwrap_or_default()
+    };
+    let [r, g, b] = rgb.map(level);
+    let cube = 16 + 36 * r + 6 * g + b;
+
+    let average = rgb.iter().map(|&c| u16::from(c)).sum::<u16>() / 3;
+    let gray = 232 + u8::try_from(average.saturating_sub(3) / 10).map_or(23, |step| step.min(23));
+
+    if distance(rgb, indexed_rgb(gray)) < distance(rgb, indexed_rgb(cube)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+/// Returns the ANSI color nearest to `rgb`.
+fn nearest_ansi(rgb: [u8; 3]) -> Color {
+    ANSI.iter()
+        .min_by_key(|(_, ansi)| distance(rgb, *ansi))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
+
+/// Returns the squared euclidean distance between two colors.
+fn distance(a: [u8; 3], b: [u8; 3]) -> u32 {
+    a.iter()
+        .zip(b)
+        .map(|(&a, b)| u32::from(a.abs_diff(b)).pow(2))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_convert_to_rgb() {
+        assert_eq!(rgb(Color::Rgb(1, 2, 3)), Some([1, 2, 3]));
+        assert_eq!(rgb(Color::LightRed), Some([255, 0, 0]));
+        assert_eq!(rgb(Color::Indexed(9)), Some([255, 0, 0]));
+        assert_eq!(rgb(Color::Indexed(196)), Some([255, 0, 0]));
+        assert_eq!(rgb(Color::Indexed(244)), Some([128, 128, 128]));
+        assert_eq!(rgb(Color::Reset), None);
+    }
+
+    #[test]
+    fn lerp_interpolates() {
+        assert_eq!(lerp([0, 100, 255], [255, 200, 0], 0.0), [0, 100, 255]);
+        assert_eq!(lerp([0, 100, 255], [255, 200, 0], 0.5), [128, 150, 128]);
+        assert_eq!(lerp([0, 100, 255], [255, 200, 0], 2.0), [255, 200, 0]);
+    }
+
+    #[test]
+    fn quantize_finds_nearest_color() {
+        assert_eq!(
+            ColorDepth::TrueColor.quantize([1, 2, 3]),
+            Color::Rgb(1, 2, 3)
+        );
+        assert_eq!(
+            ColorDepth::Indexed.quantize([250, 5, 5]),
+            Color::Indexed(196)
+        );
+        assert_eq!(
+            ColorDepth::Indexed.quantize([130, 128, 126]),
+            Color::Indexed(244)
+        );
+        assert_eq!(ColorDepth::Ansi.quantize([250, 5, 5]), Color::LightRed);
+        assert_eq!(ColorDepth::Ansi.quantize([100, 100, 110]), Color::DarkGray);
+    }
+}
@@ -0,0 +1,379 @@
+use std::any::Any;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+};
+
+use crate::{Garnish, HitPart, RenderModifier};
+
+bitflags::bitflags! {
+    /// Per-frame state of a widget, passed to garnishes in a [`RenderContext`].
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(transparent))]
+    #[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct ContextFlags: u8 {
+        const NONE     = 0b0000;
+        const FOCUSED  = 0b0001;
+        const HOVERED  = 0b0010;
+        const DISABLED = 0b0100;
+        const ACTIVE   = 0b1000;
+    }
+}
+
+/// State passed to garnishes when rendering a garnished widget.
+///
+/// Holds [`ContextFlags`] describing the widget's state this frame, a
+/// frame clock for animated garnishes and optional user data garnishes can
+/// downcast. Render with a context using `render_with_context`; rendering
+/// a garnished widget the usual way uses the default, empty context.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect, style::{Color, Style}, text::Line};
+/// use ratatui_garnish::{
+///     Conditional, GarnishableWidget, RenderContext, border::DoubleBorder, border::PlainBorder,
+/// };
+///
+/// let widget = Line::raw("Pane")
+///     .garnish(Conditional::when_focused(DoubleBorder::default()))
+///     .garnish(Conditional::unless_focused(PlainBorder::default()));
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+/// widget.render_with_context(buffer.area, &mut buffer, &RenderContext::new().focused(true));
+/// assert_eq!(buffer[(0, 0)].symbol(), "╔");
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RenderContext<'a> {
+    /// The state of the widget.
+    pub flags: ContextFlags,
+    /// The frame clock driving animated garnishes.
+    pub tick: u64,
+    data: Option<&'a dyn Any>,
+}
+
+impl<'a> RenderContext<'a> {
+    /// Creates an empty context.
+    #[must_use = "constructor returns a new instance"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the flags of the context.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn flags(mut self, flags: ContextFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Sets the frame clock.
+    ///
+    /// Animated garnishes, like those in the [`animation`](crate::animation)
+    /// module, derive their frame from the tick; other garnishes ignore it.
+    /// Increase it every frame, or derive it from the elapsed time.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn tick(mut self, tick: u64) -> Self {
+        self.tick = tick;
+        self
+    }
+
+    /// Sets or clears [`ContextFlags::FOCUSED`].
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.flags.set(ContextFlags::FOCUSED, focused);
+        self
+    }
+
+    /// Sets or clears [`ContextFlags::HOVERED`].
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub fn hovered(mut self, hovered: bool) -> Self {
+        self.flags.set(ContextFlags::HOVERED, hovered);
+        self
+    }
+
+    /// Sets or clears [`ContextFlags::DISABLED`].
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.flags.set(ContextFlags::DISABLED, disabled);
+        self
+    }
+
+    /// Sets or clears [`ContextFlags::ACTIVE`].
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub fn active(mut self, active: bool) -> Self {
+        self.flags.set(ContextFlags::ACTIVE, active);
+        self
+    }
+
+    /// Attaches user data to the context.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_garnish::RenderContext;
+    ///
+    /// let selected = 3_usize;
+    /// let context = RenderContext::new().with_data(&selected);
+    /// assert_eq!(context.data::<usize>(), Some(&3));
+    /// assert_eq!(context.data::<u8>(), None);
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn with_data(mut self, data: &'a dyn Any) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Returns the user data if it is of type `T`.
+    #[must_use]
+    pub fn data<T: Any>(&self) -> Option<&'a T> {
+        self.data.and_then(<dyn Any>::downcast_ref)
+    }
+
+    /// Returns `true` if all of `flags` are set.
+    #[must_use]
+    pub const fn contains(&self, flags: ContextFlags) -> bool {
+        self.flags.contains(flags)
+    }
+}
+
+/// A garnish that is only applied depending on the [`RenderContext`].
+///
+/// `Conditional` wraps another garnish and applies it when all of its
+/// `when` flags and none of its `unless` flags are set. This way one
+/// [`Garnishes`](crate::Garnishes) value describes e.g. both the focused
+/// and the unfocused look of a widget.
+///
+/// Without a context, e.g. when rendered with `render` or in layout
+/// queries like `inner_area`, the default context is used. Pass the
+/// context to the queries ending in `_with`, like `inner_area_with`, to
+/// get the layout the widget is rendered with.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use ratatui_garnish::{Conditional, ContextFlags, garnishes};
+///
+/// let garnishes = garnishes![
+///     Conditional::when(ContextFlags::FOCUSED, Style::default().fg(Color::Yellow)),
+///     Conditional::when(ContextFlags::HOVERED, Style::default().bg(Color::DarkGray))
+///         .unless(ContextFlags::DISABLED),
+/// ];
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Conditional<'a> {
+    when: ContextFlags,
+    #[cfg_attr(feature = "serde", serde(default))]
+    unless: ContextFlags,
+    garnish: Box<Garnish<'a>>,
+}
+
+impl<'a> Conditional<'a> {
+    /// Creates a garnish applied only when all of `flags` are set.
+    #[must_use = "constructor returns a new instance"]
+    pub fn when<G: Into<Garnish<'a>>>(flags: ContextFlags, garnish: G) -> Self {
+        Self {
+            when: flags,
+            unless: ContextFlags::NONE,
+            garnish: Box::new(garnish.into()),
+        }
+    }
+
+    /// Creates a garnish applied only when the widget is focused.
+    #[must_use = "constructor returns a new instance"]
+    pub fn when_focused<G: Into<Garnish<'a>>>(garnish: G) -> Self {
+        Self::when(ContextFlags::FOCUSED, garnish)
+    }
+
+    /// Creates a garnish applied only when the widget is not focused.
+    #[must_use = "constructor returns a new instance"]
+    pub fn unless_focused<G: Into<Garnish<'a>>>(garnish: G) -> Self {
+        Self::when(ContextFlags::NONE, garnish).unless(ContextFlags::FOCUSED)
+    }
+
+    /// Only applies the garnish when none of `flags` are set.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn unless(mut self, flags: ContextFlags) -> Self {
+        self.unless = flags;
+        self
+    }
+
+    /// Returns the wrapped garnish.
+    #[must_use]
+    pub fn garnish(&self) -> &Garnish<'a> {
+        &self.garnish
+    }
+
+    /// Returns `true` if the garnish is applied in `context`.
+    #[must_use]
+    pub const fn applies(&self, context: &RenderContext) -> bool {
+        context.contains(self.when) && !context.flags.intersects(self.unless)
+    }
+}
+
+impl RenderModifier for Conditional<'_> {
+    fn modify_area(&self, area: Rect) -> Rect {
+        self.modify_area_with(area, &RenderContext::default())
+    }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        self.expand_area_with(area, &RenderContext::default())
+    }
+
+    fn before_render(&self, area: Rect, buf: &mut Buffer) {
+        self.before_render_with(area, buf, &RenderContext::default());
+    }
+
+    fn after_render(&self, area: Rect, buf: &mut Buffer) {
+        self.after_render_with(area, buf, &RenderContext::default());
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        self.hit_test_with(area, position, &RenderContext::default())
+    }
+
+    fn modify_area_with(&self, area: Rect, context: &RenderContext) -> Rect {
+        if self.applies(context) {
+            self.garnish.modify_area_with(area, context)
+        } else {
+            area
+        }
+    }
+
+    fn expand_area_with(&self, area: Rect, context: &RenderContext) -> Rect {
+        if self.applies(context) {
+            self.garnish.expand_area_with(area, context)
+        } else {
+            area
+        }
+    }
+
+    fn before_render_with(&self, area: Rect, buf: &mut Buffer, context: &RenderContext) {
+        if self.applies(context) {
+            self.garnish.before_render_with(area, buf, context);
+        }
+    }
+
+    fn after_render_with(&self, area: Rect, buf: &mut Buffer, context: &RenderContext) {
+        if self.applies(context) {
+            self.garnish.after_render_with(area, buf, context);
+        }
+    }
+
+    fn hit_test_with(
+        &self,
+        area: Rect,
+        position: Position,
+        context: &RenderContext,
+    ) -> Option<HitPart> {
+        if self.applies(context) {
+            self.garnish.hit_test_with(area, position, context)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GarnishLayout, GarnishableWidget, Hit, Padding, border::PlainBorder};
+    use ratatui::{
+        style::{Color, Style},
+        text::Line,
+    };
+
+    #[test]
+    fn conditional_applies_flags() {
+        let conditional = Conditional::when(ContextFlags::HOVERED, Style::default())
+            .unless(ContextFlags::DISABLED | ContextFlags::ACTIVE);
+
+        assert!(!conditional.applies(&RenderContext::new()));
+        assert!(conditional.applies(&RenderContext::new().hovered(true)));
+        assert!(!conditional.applies(&RenderContext::new().hovered(true).active(true)));
+        assert!(Conditional::unless_focused(Style::default()).applies(&RenderContext::new()));
+    }
+
+    #[test]
+    fn render_with_context_applies_conditional_garnishes() {
+        let area = Rect::new(0, 0, 6, 3);
+        let widget = Line::raw("Test")
+            .garnish(Conditional::when_focused(Padding::uniform(1)))
+            .garnish(Conditional::when_focused(
+                Conditional::when(ContextFlags::NONE, Style::default().bg(Color::Blue))
+                    .unless(ContextFlags::DISABLED),
+            ));
+
+        let mut buffer = Buffer::empty(area);
+        widget.render_ref_with_context(area, &mut buffer, &RenderContext::new());
+        assert_eq!(buffer[(0, 0)].symbol(), "T");
+        assert_eq!(buffer[(0, 0)].bg, Color::Reset);
+
+        let mut buffer = Buffer::empty(area);
+        let focused = RenderContext::new().focused(true);
+        widget.render_ref_with_context(area, &mut buffer, &focused);
+        assert_eq!(buffer[(1, 1)].symbol(), "T");
+        assert_eq!(buffer[(1, 1)].bg, Color::Blue);
+
+        let mut buffer = Buffer::empty(area);
+        widget.render_with_context(area, &mut buffer, &focused.disabled(true));
+        assert_eq!(buffer[(1, 1)].symbol(), "T");
+        assert_eq!(buffer[(1, 1)].bg, Color::Reset);
+    }
+
+    #[test]
+    fn queries_with_context_apply_conditional_garnishes() {
+        let area = Rect::new(0, 0, 10, 5);
+        let widget = Line::raw("Test")
+            .garnish(Conditional::when_focused(PlainBorder::default()))
+            .garnish(Conditional::unless_focused(Padding::horizontal(2)));
+        let focused = RenderContext::new().focused(true);
+
+        assert_eq!(widget.inner_area(area), Rect::new(2, 0, 6, 5));
+        assert_eq!(
+            widget.inner_area_with(area, &focused),
+            Rect::new(1, 1, 8, 3)
+        );
+        assert_eq!(
+            widget.areas_with(area, &focused).collect::<Vec<_>>(),
+            vec![area, Rect::new(1, 1, 8, 3)]
+        );
+        assert_eq!(
+            widget.outer_area_with(Rect::new(1, 1, 8, 3), &focused),
+            area
+        );
+        assert_eq!(widget.outer_area(Rect::new(2, 0, 6, 5)), area);
+
+        let position = Position::new(0, 0);
+        assert_eq!(
+            widget.hit_test(area, position),
+            Some(Hit::Garnish {
+                index: 1,
+                part: HitPart::Area,
+            })
+        );
+        assert!(matches!(
+            widget.hit_test_with(area, position, &focused),
+            Some(Hit::Garnish {
+                index: 0,
+                part: HitPart::Border(_)
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn conditional_serialization() {
+        let conditional = Conditional::when_focused(Padding::uniform(1));
+        let json = serde_json::to_string(&conditional).unwrap();
+
+        let restored: Conditional = serde_json::from_str(&json).unwrap();
+        assert!(restored.applies(&RenderContext::new().focused(true)));
+        assert_eq!(
+            restored.garnish().try_as_padding_ref(),
+            Some(&Padding::uniform(1))
+        );
+    }
+}
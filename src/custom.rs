@@ -0,0 +1,438 @@
+//! User-defined garnishes.
+//!
+//! [`Garnish`](crate::Garnish) is an enum of the garnishes in this crate. To
+//! use your own garnish alongside them implement [`CustomGarnish`] for it
+//! and wrap it in [`Custom`]:
+//!
+//! ```rust
+//! use ratatui::{buffer::Buffer, layout::Rect, text::Line};
+//! use ratatui_garnish::{
+//!     GarnishableWidget, RenderModifier, border::PlainBorder,
+//!     custom::{Custom, CustomGarnish},
+//! };
+//!
+//! /// Draws a `●` in the top right corner.
+//! #[derive(Debug)]
+//! struct Badge;
+//!
+//! impl RenderModifier for Badge {
+//!     fn after_render(&self, area: Rect, buf: &mut Buffer) {
+//!         if !area.is_empty() {
+//!             buf[(area.right() - 1, area.y)].set_symbol("●");
+//!         }
+//!     }
+//! }
+//!
+//! impl CustomGarnish for Badge {}
+//!
+//! let widget = Line::raw("Inbox")
+//!     .garnish(Custom::new(Badge))
+//!     .garnish(PlainBorder::default());
+//!
+//! assert!(widget[0].try_as_custom_ref().unwrap().downcast_ref::<Badge>().is_some());
+//! ```
+//!
+//! Built-in garnishes are matched directly, only custom garnishes are
+//! called through a trait object.
+//!
+//! # Serde
+//!
+//! With the `serde` feature custom garnishes are serialized by name as
+//! `{ "name": ..., "data": ... }`. `register` a type under a name before
+//! serializing or deserializing it:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use ratatui_garnish::{RenderModifier, custom::{self, Custom, CustomGarnish}};
+//!
+//! #[derive(Debug, serde::Serialize, serde::Deserialize)]
+//! struct Badge {
+//!     symbol: String,
+//! }
+//!
+//! impl RenderModifier for Badge {}
+//! impl CustomGarnish for Badge {}
+//!
+//! custom::register::<Badge>("badge");
+//!
+//! let custom: Custom = serde_json::from_str(r#"{ "name": "badge", "data": { "symbol": "●" } }"#)
+//!     .unwrap();
+//! assert_eq!(custom.downcast_ref::<Badge>().unwrap().symbol, "●");
+//! # }
+//! ```
+//!
+//! The registry is global to the process: a registration is visible to all
+//! threads, including the other tests of a test binary, and lasts until the
+//! type or name is registered again. Libraries should prefix the names they
+//! register, like `"mylib.badge"`, so they don't replace each other's types.
+
+use std::{any::Any, fmt, sync::Arc};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+};
+
+use crate::{HitPart, RenderContext, RenderModifier};
+
+/// A garnish defined outside of this crate.
+///
+/// Implement it for a [`RenderModifier`] to wrap the type in [`Custom`].
+pub trait CustomGarnish: RenderModifier + Any + fmt::Debug + Send + Sync {}
+
+/// Wraps a [`CustomGarnish`] so it can be used as a [`Garnish`](crate::Garnish).
+///
+/// Cloning a `Custom` shares the wrapped garnish.
+#[derive(Debug, Clone)]
+pub struct Custom(Arc<dyn CustomGarnish>);
+
+impl Custom {
+    /// Wraps `garnish`.
+    #[must_use = "constructor returns a new instance"]
+    pub fn new<G: CustomGarnish>(garnish: G) -> Self {
+        Self(Arc::new(garnish))
+    }
+
+    /// Returns the wrapped garnish.
+    #[must_use]
+    pub fn get(&self) -> &dyn CustomGarnish {
+        &*self.0
+    }
+
+    /// Returns the wrapped garnish if it is of type `T`.
+    #[must_use]
+    pub fn downcast_ref<T: CustomGarnish>(&self) -> Option<&T> {
+        let garnish: &dyn Any = &*self.0;
+        garnish.downcast_ref()
+    }
+
+    /// Returns `true` if the wrapped garnish is of type `T`.
+    #[must_use]
+    pub fn is<T: CustomGarnish>(&self) -> bool {
+        self.downcast_ref::<T>().is_some()
+    }
+}
+
+impl<G: CustomGarnish> From<Arc<G>> for Custom {
+    fn from(garnish: Arc<G>) -> Self {
+        Self(garnish)
+    }
+}
+
+impl RenderModifier for Custom {
+    fn modify_area(&self, area: Rect) -> Rect {
+        self.0.modify_area(area)
+    }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        self.0.expand_area(area)
+    }
+
+    fn before_render(&self, area: Rect, buf: &mut Buffer) {
+        self.0.before_render(area, buf);
+    }
+
+    fn after_render(&self, area: Rect, buf: &mut Buffer) {
+        self.0.after_render(area, buf);
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        self.0.hit_test(area, position)
+    }
+
+    fn modify_area_with(&self, area: Rect, context: &RenderContext) -> Rect {
+        self.0.modify_area_with(area, context)
+    }
+
+    fn expand_area_with(&self, area: Rect, context: &RenderContext) -> Rect {
+        self.0.expand_area_with(area, context)
+    }
+
+    fn before_render_with(&self, area: Rect, buf: &mut Buffer, context: &RenderContext) {
+        self.0.before_render_with(area, buf, context);
+    }
+
+    fn after_render_with(&self, area: Rect, buf: &mut Buffer, context: &RenderContext) {
+        self.0.after_render_with(area, buf, context);
+    }
+
+    fn hit_test_with(
+        &self,
+        area: Rect,
+        position: Position,
+        context: &RenderContext,
+    ) -> Option<HitPart> {
+        self.0.hit_test_with(area, position, context)
+    }
+}
+
+// ===== Serde =====
+
+#[cfg(feature = "serde")]
+pub use registry::register;
+
+#[cfg(feature = "serde")]
+mod registry {
+    use std::{
+        any::{Any, TypeId},
+        collections::HashMap,
+        fmt,
+        sync::{LazyLock, PoisonError, RwLock},
+    };
+
+    use serde::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{
+            self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess,
+            Visitor,
+        },
+        ser::{self, SerializeStruct},
+    };
+
+    use super::{Custom, CustomGarnish};
+    use crate::value::Value;
+
+    type SerializeFn = fn(&dyn Any) -> Option<&dyn erased_serde::Serialize>;
+    type DeserializeFn =
+        fn(&mut dyn erased_serde::Deserializer) -> Result<Custom, erased_serde::Error>;
+
+    #[derive(Default)]
+    struct Registry {
+        names: HashMap<TypeId, (String, SerializeFn)>,
+        types: HashMap<String, DeserializeFn>,
+    }
+
+    /// The registrations of all threads.
+    static REGISTRY: LazyLock<RwLock<Registry>> = LazyLock::new(RwLock::default);
+
+    /// Registers `T` under `name` for serialization and deserialization.
+    ///
+    /// The registry is shared by the whole process. Registering a type or
+    /// name again replaces the previous registration, also when another
+    /// library registered it.
+    pub fn register<T>(name: impl Into<String>)
+    where
+        T: CustomGarnish + Serialize + DeserializeOwned,
+    {
+        let name = name.into();
+        let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+        if let Some((previous, _)) = registry.names.get(&TypeId::of::<T>()) {
+            let previous = previous.clone();
+            registry.types.remove(&previous);
+        }
+        registry.names.insert(
+            TypeId::of::<T>(),
+            (name.clone(), |garnish| {
+                garnish
+                    .downcast_ref::<T>()
+                    .map(|garnish| garnish as &dyn erased_serde::Serialize)
+            }),
+        );
+        registry.types.insert(name, |deserializer| {
+            erased_serde::deserialize::<T>(deserializer).map(Custom::new)
+        });
+    }
+
+    impl Serialize for Custom {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let garnish: &dyn Any = &*self.0;
+            let registered = REGISTRY
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .names
+                .get(&garnish.type_id())
+                .cloned();
+            let Some((name, data)) =
+                registered.and_then(|(name, serialize)| Some((name, serialize(garnish)?)))
+            else {
+                return Err(ser::Error::custom(format!(
+                    "custom garnish {:?} is not registered",
+                    self.0
+                )));
+            };
+
+            let mut state = serializer.serialize_struct("Custom", 2)?;
+            state.serialize_field("name", &name)?;
+            state.serialize_field("data", data)?;
+            state.end()
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Custom {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_struct("Custom", &["name", "data"], CustomVisitor)
+        }
+    }
+
+    struct CustomVisitor;
+
+    impl<'de> Visitor<'de> for CustomVisitor {
+        type Value = Custom;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a custom garnish with a name and its data")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Custom, A::Error> {
+            let name: String = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            seq.next_element_seed(Data::new(&name)?)?
+                .ok_or_else(|| de::Error::invalid_length(1, &self))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Custom, A::Error> {
+            let mut name: Option<String> = None;
+            let mut custom = None;
+            // data read before the name, deserialized once the name is known
+            let mut buffered = None;
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "name" if name.is_some() => return Err(de::Error::duplicate_field("name")),
+                    "data" if custom.is_some() || buffered.is_some() => {
+                        return Err(de::Error::duplicate_field("data"));
+                    }
+                    "name" => name = Some(map.next_value()?),
+                    "data" => match &name {
+                        Some(name) => custom = Some(map.next_value_seed(Data::new(name)?)?),
+                        None => buffered = Some(map.next_value::<Value>()?),
+                    },
+                    _ => return Err(de::Error::unknown_field(&key, &["name", "data"])),
+                }
+            }
+            let name = name.ok_or_else(|| de::Error::missing_field("name"))?;
+            match (custom, buffered) {
+                (Some(custom), _) => Ok(custom),
+                (None, Some(data)) => Data::new(&name)?.deserialize(data.into_deserializer()),
+                (None, None) => Err(de::Error::missing_field("data")),
+            }
+        }
+    }
+
+    /// Deserializes the data of the custom garnish registered as `name`.
+    struct Data(DeserializeFn);
+
+    impl Data {
+        fn new<E: de::Error>(name: &str) -> Result<Self, E> {
+            let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+            registry
+                .types
+                .get(name)
+                .map(|deserialize| Self(*deserialize))
+                .ok_or_else(|| E::custom(format!("unknown custom garnish `{name}`")))
+        }
+    }
+
+    impl<'de> DeserializeSeed<'de> for Data {
+        type Value = Custom;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Custom, D::Error> {
+            let mut deserializer = <dyn erased_serde::Deserializer>::erase(deserializer);
+            (self.0)(&mut deserializer).map_err(de::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GarnishLayout, GarnishableWidget, Hit, Padding};
+    use ratatui::{text::Line, widgets::WidgetRef};
+
+    /// Reserves a column on the right and draws a symbol in it.
+    #[derive(Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct Badge {
+        symbol: String,
+    }
+
+    impl RenderModifier for Badge {
+        fn modify_area(&self, area: Rect) -> Rect {
+            Rect {
+                width: area.width.saturating_sub(1),
+                ..area
+            }
+        }
+
+        fn after_render(&self, area: Rect, buf: &mut Buffer) {
+            if !area.is_empty() {
+                buf[(area.right() - 1, area.y)].set_symbol(&self.symbol);
+            }
+        }
+    }
+
+    impl CustomGarnish for Badge {}
+
+    fn badge() -> Badge {
+        Badge {
+            symbol: "●".to_string(),
+        }
+    }
+
+    #[test]
+    fn custom_garnishes_render_with_built_ins() {
+        let area = Rect::new(0, 0, 6, 3);
+        let widget = Line::raw("Inbox")
+            .garnish(Padding::top(1))
+            .garnish(Custom::new(badge()));
+
+        let mut buffer = Buffer::empty(area);
+        widget.render_ref(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["", "Inbox●", ""]));
+
+        assert_eq!(widget.inner_area(area), Rect::new(0, 1, 5, 2));
+        assert_eq!(
+            widget.hit_test(area, Position::new(5, 1)),
+            Some(Hit::Garnish {
+                index: 1,
+                part: HitPart::Area
+            })
+        );
+    }
+
+    #[test]
+    fn custom_downcasts_to_wrapped_type() {
+        let custom = Custom::new(badge());
+        assert!(custom.is::<Badge>());
+        assert_eq!(custom.downcast_ref::<Badge>(), Some(&badge()));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn custom_serialization() {
+        #[derive(Debug, serde::Serialize, serde::Deserialize)]
+        struct Unregistered;
+        impl RenderModifier for Unregistered {}
+        impl CustomGarnish for Unregistered {}
+
+        register::<Badge>("test-badge");
+
+        let garnishes: crate::Garnishes = vec![
+            crate::Garnish::from(Padding::left(1)),
+            crate::Garnish::from(Custom::new(badge())),
+        ]
+        .into();
+        let json = serde_json::to_string(&garnishes).unwrap();
+        assert!(json.contains(r#"{"Custom":{"name":"test-badge","data":{"symbol":"●"}}}"#));
+
+        let restored: crate::Garnishes = serde_json::from_str(&json).unwrap();
+        let custom = restored[1].try_as_custom_ref().unwrap();
+        assert_eq!(custom.downcast_ref::<Badge>(), Some(&badge()));
+
+        assert!(serde_json::to_string(&Custom::new(Unregistered)).is_err());
+        assert!(serde_json::from_str::<Custom>(r#"{"name":"nope","data":null}"#).is_err());
+
+        // the data may come before the name
+        let custom: Custom =
+            serde_json::from_str(r#"{"data":{"symbol":"○"},"name":"test-badge"}"#).unwrap();
+        assert_eq!(
+            custom.downcast_ref::<Badge>(),
+            Some(&Badge {
+                symbol: "○".to_string()
+            })
+        );
+        assert!(serde_json::from_str::<Custom>(r#"{"data":null,"name":"nope"}"#).is_err());
+        assert!(serde_json::from_str::<Custom>(r#"{"data":null}"#).is_err());
+    }
+}
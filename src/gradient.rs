@@ -0,0 +1,340 @@
+//! Gradient garnish
+//!
+//! A [`Gradient`] interpolates between two colors and sets the foreground
+//! or background of every cell it covers. It can fill its whole area
+//! horizontally, vertically or diagonally, or follow the perimeter of the
+//! area to color a border.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::{style::Color, text::Text};
+//! use ratatui_garnish::{GarnishableWidget, border::RoundedBorder, gradient::Gradient};
+//!
+//! let widget = Text::raw("Hello, world!")
+//!     .garnish(Gradient::border(Color::Rgb(255, 0, 128), Color::Rgb(0, 128, 255)))
+//!     .garnish(RoundedBorder::default());
+//! ```
+//!
+//! A border gradient only colors the cells on the edge of its area, the
+//! same cells a [`Border`](crate::border::Border) draws on when it follows
+//! the gradient. Use `borders` to match a border that doesn't draw all sides.
+//! Garnishes after the gradient that set the same colors, like a `Style`,
+//! overwrite it.
+//!
+//! # Color Depth
+//!
+//! Gradients are computed in RGB. For terminals without true color support
+//! set a [`ColorDepth`] to quantize the colors to the 256 color palette or
+//! the 16 ANSI colors.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Color, Style},
+};
+
+use crate::{ColorDepth, RenderModifier, border::Borders, color};
+
+/// The direction in which the colors of a [`Gradient`] change.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GradientDirection {
+    /// From left to right, the default.
+    #[default]
+    Horizontal,
+    /// From top to bottom.
+    Vertical,
+    /// From the top left to the bottom right corner.
+    Diagonal,
+    /// Along the perimeter of the area, from the top left corner to the
+    /// bottom right corner in both directions. Only the cells on the edge
+    /// of the area are colored.
+    Border,
+}
+
+/// Which colors of the cells a [`Gradient`] sets.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GradientTarget {
+    /// The foreground color, the default.
+    #[default]
+    Foreground,
+    /// The background color.
+    Background,
+    /// Both the foreground and background color.
+    Both,
+}
+
+impl GradientTarget {
+    /// Returns a style setting the target colors to `color`.
+    pub(crate) const fn style(self, color: Color) -> Style {
+        match self {
+            Self::Foreground => Style::new().fg(color),
+            Self::Background => Style::new().bg(color),
+            Self::Both => Style::new().fg(color).bg(color),
+        }
+    }
+}
+
+/// A garnish coloring cells with a gradient between two colors.
+///
+/// `Color::Reset` can't be interpolated; when either color is `Reset` the
+/// gradient switches between the two colors halfway.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Color;
+/// use ratatui_garnish::{ColorDepth, gradient::{Gradient, GradientDirection, GradientTarget}};
+///
+/// let gradient = Gradient::new(Color::Blue, Color::Cyan)
+///     .direction(GradientDirection::Diagonal)
+///     .target(GradientTarget::Background)
+///     .color_depth(ColorDepth::Indexed);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Gradient {
+    /// The color at the start of the gradient.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_color")
+    )]
+    pub start: Color,
+    /// The color at the end of the gradient.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_color")
+    )]
+    pub end: Color,
+    /// The direction of the gradient, defaults to `Horizontal`.
+    pub direction: GradientDirection,
+    /// Which colors to set, defaults to `Foreground`.
+    pub target: GradientTarget,
+    /// The colors available, defaults to `TrueColor`.
+    pub color_depth: ColorDepth,
+    /// The sides colored by a `Border` gradient, defaults to `Borders::ALL`.
+    pub borders: Borders,
+}
+
+impl Default for Gradient {
+    /// Creates a horizontal foreground gradient from `Color::Reset` to `Color::Reset`.
+    fn default() -> Self {
+        Self {
+            start: Color::Reset,
+            end: Color::Reset,
+            direction: GradientDirection::Horizontal,
+            target: GradientTarget::Foreground,
+            color_depth: ColorDepth::TrueColor,
+            borders: Borders::ALL,
+        }
+    }
+}
+
+impl Gradient {
+    /// Creates a horizontal foreground gradient from `start` to `end`.
+    #[must_use = "constructor returns a new instance"]
+    pub fn new(start: Color, end: Color) -> Self {
+        Self {
+            start,
+            end,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a foreground gradient along the perimeter, for a border.
+    #[must_use = "constructor returns a new instance"]
+    pub fn border(start: Color, end: Color) -> Self {
+        Self::new(start, end).direction(GradientDirection::Border)
+    }
+
+    /// Sets the direction of the gradient.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn direction(mut self, direction: GradientDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets which colors of the cells the gradient sets.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn target(mut self, target: GradientTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Sets the colors available to the gradient.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = color_depth;
+        self
+    }
+
+    /// Sets the sides colored by a `Border` gradient.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use ratatui_garnish::{border::{Borders, PlainBorder}, gradient::Gradient};
+    ///
+    /// let border = PlainBorder::new(Borders::TOP | Borders::BOTTOM);
+    /// let gradient = Gradient::border(Color::Red, Color::Yellow).borders(*border);
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn borders(mut self, borders: Borders) -> Self {
+        self.borders = borders;
+        self
+    }
+
+    /// Returns the color of the gradient at `position` when rendered in `area`.
+    ///
+    /// Returns `None` for positions the gradient doesn't color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{layout::{Position, Rect}, style::Color};
+    /// use ratatui_garnish::gradient::Gradient;
+    ///
+    /// let gradient = Gradient::new(Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0));
+    /// let area = Rect::new(0, 0, 5, 1);
+    /// assert_eq!(gradient.color_at(area, Position::new(2, 0)), Some(Color::Rgb(100, 0, 0)));
+    /// ```
+    #[must_use]
+    pub fn color_at(&self, area: Rect, position: Position) -> Option<Color> {
+        let t = self.progress(area, position)?;
+        Some(color::interpolate(
+            self.start,
+            self.end,
+            t,
+            self.color_depth,
+        ))
+    }
+
+    /// Returns how far along the gradient `position` is, from 0.0 to 1.0.
+    fn progress(&self, area: Rect, position: Position) -> Option<f32> {
+        if !area.contains(position) {
+            return None;
+        }
+        let x = position.x - area.x;
+        let y = position.y - area.y;
+        let (right, bottom) = (area.width - 1, area.height - 1);
+
+        let fraction = |value: u16, max: u16| {
+            if max == 0 {
+                0.0
+            } else {
+                f32::from(value) / f32::from(max)
+            }
+        };
+
+        match self.direction {
+            GradientDirection::Horizontal => Some(fraction(x, right)),
+            GradientDirection::Vertical => Some(fraction(y, bottom)),
+            GradientDirection::Diagonal => {
+                Some(f32::midpoint(fraction(x, right), fraction(y, bottom)))
+            }
+            GradientDirection::Border => {
+                let mut sides = Borders::NONE;
+                sides.set(Borders::LEFT, x == 0);
+                sides.set(Borders::TOP, y == 0);
+                sides.set(Borders::RIGHT, x == right);
+                sides.set(Borders::BOTTOM, y == bottom);
+                if !self.borders.intersects(sides) {
+                    return None;
+                }
+                // the distance along the edge from the top left corner is
+                // the same clockwise and counterclockwise
+                Some(fraction(x + y, right + bottom))
+            }
+        }
+    }
+}
+
+impl RenderModifier for Gradient {
+    fn before_render(&self, area: Rect, buf: &mut Buffer) {
+        for position in area.intersection(buf.area).positions() {
+            let Some(color) = self.color_at(area, position) else {
+                continue;
+            };
+            buf[position].set_style(self.target.style(color));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gradient_fills_area() {
+        let gradient = Gradient::new(Color::Rgb(0, 0, 0), Color::Rgb(0, 0, 200))
+            .direction(GradientDirection::Diagonal)
+            .target(GradientTarget::Background);
+        let area = Rect::new(1, 1, 3, 3);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        gradient.before_render(area, &mut buffer);
+
+        let bg = |x, y| buffer[(x, y)].bg;
+        assert_eq!(bg(0, 0), Color::Reset);
+        assert_eq!(bg(1, 1), Color::Rgb(0, 0, 0));
+        assert_eq!(bg(2, 1), Color::Rgb(0, 0, 50));
+        assert_eq!(bg(2, 2), Color::Rgb(0, 0, 100));
+        assert_eq!(bg(3, 3), Color::Rgb(0, 0, 200));
+        assert_eq!(buffer[(3, 3)].fg, Color::Reset);
+    }
+
+    #[test]
+    fn border_gradient_follows_perimeter() {
+        let gradient = Gradient::border(Color::Rgb(0, 0, 0), Color::Rgb(0, 120, 0));
+        let area = Rect::new(0, 0, 5, 4);
+
+        let green = |x, y| {
+            gradient
+                .color_at(area, Position::new(x, y))
+                .map(|color| color::rgb(color).unwrap()[1])
+        };
+        assert_eq!(green(0, 0), Some(0));
+        assert_eq!(green(4, 0), Some(69));
+        assert_eq!(green(0, 3), Some(51));
+        assert_eq!(green(4, 3), Some(120));
+        assert_eq!(green(2, 2), None);
+
+        let top = gradient.borders(Borders::TOP);
+        assert!(top.color_at(area, Position::new(0, 0)).is_some());
+        assert!(top.color_at(area, Position::new(0, 1)).is_none());
+    }
+
+    #[test]
+    fn gradient_quantizes_colors() {
+        let gradient = Gradient::new(Color::Black, Color::LightRed).color_depth(ColorDepth::Ansi);
+        let area = Rect::new(0, 0, 3, 1);
+
+        assert_eq!(
+            gradient.color_at(area, Position::new(1, 0)),
+            Some(Color::Red)
+        );
+        assert_eq!(
+            Gradient::new(Color::Reset, Color::Blue).color_at(area, Position::new(2, 0)),
+            Some(Color::Blue)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn gradient_serialization() {
+        let gradient = Gradient::border(Color::Red, Color::Rgb(0, 128, 255))
+            .color_depth(ColorDepth::Indexed)
+            .borders(Borders::TOP | Borders::LEFT);
+        let json = serde_json::to_string(&gradient).unwrap();
+
+        let restored: Gradient = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, gradient);
+
+        let partial: Gradient =
+            serde_json::from_str(r##"{ "start": "red", "end": "#0080ff" }"##).unwrap();
+        assert_eq!(partial, Gradient::new(Color::Red, Color::Rgb(0, 128, 255)));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::border::Borders;
+
+/// The garnish layer at a position, returned by `hit_test`.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{layout::{Position, Rect}, text::Line};
+/// use ratatui_garnish::{
+///     GarnishLayout, GarnishableWidget, Hit, HitPart,
+///     border::{Borders, PlainBorder},
+/// };
+///
+/// let widget = Line::raw("Click me").garnish(PlainBorder::default());
+/// let area = Rect::new(0, 0, 12, 3);
+///
+/// assert_eq!(
+///     widget.hit_test(area, Position::new(0, 0)),
+///     Some(Hit::Garnish { index: 0, part: HitPart::Border(Borders::TOP | Borders::LEFT) })
+/// );
+/// assert_eq!(widget.hit_test(area, Position::new(1, 1)), Some(Hit::Widget));
+/// assert_eq!(widget.hit_test(area, Position::new(20, 1)), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hit {
+    /// The position is in the area the widget is rendered in.
+    Widget,
+    /// The position is on the garnish at `index`.
+    Garnish {
+        /// The index of the garnish.
+        index: usize,
+        /// The part of the garnish at the position.
+        part: HitPart,
+    },
+}
+
+/// The part of a garnish at a position, returned by
+/// [`RenderModifier::hit_test`](crate::RenderModifier::hit_test).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HitPart {
+    /// Space the garnish takes from the widget without drawing on it,
+    /// e.g. padding or the blank part of a reserved title line.
+    Area,
+    /// A border; one side, or two sides for a corner.
+    Border(Borders),
+    /// The span of a title with the given index.
+    Span(usize),
+    /// The span of a title in a `TitleGroup`.
+    TitleSpan {
+        /// The index of the title in the group.
+        title: usize,
+        /// The index of the span in the title.
+        span: usize,
+    },
+    /// A shadow.
+    Shadow,
+}
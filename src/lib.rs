@@ -9,12 +9,15 @@
 //! `ratatui-garnish` provides a flexible way to change the rendering of any Ratatui widget with
 //! garnishes like borders, titles, padding, shadows, and styling. Garnishes can be layered
 //! in any order, applied at runtime, and modified without altering the underlying widget. The
-//! `GarnishedWidget` struct wraps a widget and a `Vec` of `Garnish` enums, maintaining zero-cost
-//! abstractions and type safety without trait objects.
+//! `GarnishedWidget` struct wraps a widget and a `Vec` of `Garnish` enums, so the built-in
+//! garnishes are dispatched without trait objects. Garnishes of your own use the `Custom`
+//! escape hatch, an `Arc<dyn CustomGarnish>`, and are registered by name with
+//! `custom::register::<T>` to be serialized.
 //!
-//! Want a margin outside a border? Garnish with `Padding` before a border. Need multiple borders or
-//! titles? Simply add them! Writing custom widgets but want to avoid boilerplate for styling or
-//! borders? Use `ratatui-garnish` with any widget implementing `Widget` or `StatefulWidget`.
+//! Want a margin outside a border? Garnish with `Padding` before a border, or with [`Margin`] to
+//! also clear the cells styled by earlier garnishes. Need multiple borders or titles? Simply add
+//! them! Writing custom widgets but want to avoid boilerplate for styling or borders? Use
+//! `ratatui-garnish` with any widget implementing `Widget` or `StatefulWidget`.
 //!
 //! # Example
 //!
@@ -91,7 +94,79 @@
 //! let mut widget = GarnishedWidget::from(Line::raw("Hello, World!"));
 //! widget.push(Style::default().bg(Color::Green));
 //! ````
+//!
+//! # Layout Queries
+//!
+//! Mouse handling, cursor placement or scrolling often need the area the
+//! garnished widget is rendered in. `inner_area` returns it without rendering,
+//! using the same geometry as rendering does. `areas` returns the area every
+//! garnish receives. The queries are provided by the [`GarnishLayout`] trait,
+//! implemented by [`GarnishedWidget`], [`GarnishedStatefulWidget`] and
+//! [`Garnishes`].
+//!
+//! ```rust
+//! use ratatui::{layout::Rect, text::Line};
+//! use ratatui_garnish::{GarnishLayout, GarnishableWidget, Padding, border::PlainBorder};
+//!
+//! let widget = Line::raw("Hello, World!")
+//!     .garnish(PlainBorder::default())
+//!     .garnish(Padding::horizontal(1));
+//!
+//! let area = Rect::new(0, 0, 20, 5);
+//! assert_eq!(widget.inner_area(area), Rect::new(2, 1, 16, 3));
+//! assert_eq!(widget.areas(area).nth(1), Some(Rect::new(1, 1, 18, 3)));
+//! ```
+//!
+//! The inverse, `outer_area` and `outer_size`, computes how much room the
+//! garnishes need around content of a given size, e.g. to size a popup:
+//!
+//! ```rust
+//! use ratatui::layout::{Constraint, Size};
+//! use ratatui_garnish::{
+//!     GarnishLayout, Padding, border::PlainBorder, garnishes, title::{Above, Title},
+//! };
+//!
+//! let garnishes = garnishes![
+//!     Title::<Above>::raw("Popup"),
+//!     PlainBorder::default(),
+//!     Padding::horizontal(1),
+//! ];
+//!
+//! let size = garnishes.outer_size(Size::new(20, 3));
+//! assert_eq!(size, Size::new(24, 6));
+//! let constraint = Constraint::Length(size.height);
+//! ```
+//!
+//! For mouse support `hit_test` returns what is rendered at a position: the
+//! widget, or the index of a garnish with the [`HitPart`] that was hit, like
+//! the side of a border or the span of a title.
 //
+//! # Render Context
+//!
+//! Garnishes can depend on the state of a widget, like focus or hover. Render
+//! with `render_with_context` to pass a [`RenderContext`] with [`ContextFlags`]
+//! and optional user data to the garnishes. A [`Conditional`] garnish only
+//! applies its garnish when the flags match, so one set of garnishes
+//! describes every state and doesn't need to be rebuilt each frame.
+//!
+//! ```rust
+//! use ratatui::{buffer::Buffer, layout::Rect, style::{Color, Style}, text::Line};
+//! use ratatui_garnish::{Conditional, GarnishableWidget, RenderContext, border::PlainBorder};
+//!
+//! let widget = Line::raw("Pane")
+//!     .garnish(Conditional::when_focused(Style::default().fg(Color::Yellow)))
+//!     .garnish(PlainBorder::default());
+//!
+//! let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+//! let has_focus = true;
+//! widget.render_ref_with_context(buffer.area, &mut buffer, &RenderContext::new().focused(has_focus));
+//! assert_eq!(buffer[(0, 0)].fg, Color::Yellow);
+//! ```
+//!
+//! Animated garnishes in the [`animation`] module use the `tick` of the
+//! context as a frame clock, `render_at` renders with only a tick. Other
+//! garnishes ignore it.
+//!
 //! # Available Garnishes
 //!
 //! ## Borders
@@ -103,13 +178,33 @@
 //! ## Titles
 //! - Horizontal: [`Title<Top>`] (over top border), [`Title<Bottom>`] (over bottom border), [`Title<Above>`] (reserves space above), [`Title<Below>`] (reserves space below)
 //! - Vertical: [`Title<Left>`] (over left border), [`Title<Right>`] (over right border), [`Title<Before>`] (reserves space left), [`Title<After>`] (reserves space right)
+//! - Groups: [`TitleGroup`] (several titles on one side, shortened by priority instead of overlapping)
+//! - Multi-line: [`TextTitle`] (reserves a row or column for every line of a `Text`, wrapping long lines)
 //!
 //! ## Shadows
-//! - [`Shadow`] (light `░`, medium `▒`, dark `▓`, or full `█` shades with full-character offsets)
-//! - [`HalfShadow`] (full `█` or quadrant characters with half-character offsets)
+//! - [`Shadow`] (light `░`, medium `▒`, dark `▓`, or full `█` shades with full-character offsets, or a [`Tint`](shadow::Tint) darkening the cells behind it, drawn inside or outside the area)
+//! - [`SoftShadow`] (fades from `▓` to `░`, or a fading tint, over a configurable spread)
+//! - [`HalfShadow`] (full `█` or quadrant characters with half-character offsets, solid or tinted)
+//! - [`Bevel`] (darker and lighter inside edges for inset or raised widgets)
+//!
+//! ## Gradients
+//! - [`Gradient`] (interpolates colors across the area or along a border, with a [`ColorDepth`] fallback)
 //!
 //! ## Padding
 //! - [`Padding`] (spacing around the widget), same as `Padding` from `ratatui::widgets::Block`
+//! - [`RelativePadding`] (spacing in percentages of the area with min/max clamps, or around centered content)
+//! - [`Margin`] (spacing around the widget that resets, or fills, the cells it reserves)
+//!
+//! ## Animations
+//! - [`Frames`] (cycles through garnishes, e.g. a title with a spinner)
+//! - [`MarchingAnts`] (a dashed border whose dashes move)
+//! - [`Pulse`] (a style whose color pulses)
+//!
+//! ## Conditional
+//! - [`Conditional`] (applies another garnish depending on the [`RenderContext`])
+//!
+//! ## Custom
+//! - [`Custom`] (wraps your own [`CustomGarnish`](custom::CustomGarnish), see the [`custom`] module)
 //!
 //! ## Built-in Ratatui Support
 //! - [`Style`] (background colors, text styling)
@@ -203,6 +298,21 @@
 //!     .garnishes_from_slice(widget.as_slice());
 //! ```
 //!
+//! # Themes
+//!
+//! A [`Theme`] maps names to [`Garnishes`], entries can extend other
+//! entries. Apply an entry with `garnish_from_theme`:
+//!
+//! ```rust
+//! use ratatui::text::Line;
+//! use ratatui_garnish::{GarnishableWidget, Padding, border::PlainBorder, garnishes, theme::Theme};
+//!
+//! let mut theme = Theme::new();
+//! theme.insert("panel", garnishes![PlainBorder::default(), Padding::uniform(1)]);
+//!
+//! let widget = Line::raw("Hello").garnish_from_theme(&theme, "panel");
+//! ```
+//!
 //! # Features
 //!
 //! ## Serde support
@@ -210,7 +320,13 @@
 //! Serialization & deserialization using serde can be enabled using the cargo feature
 //! `serde`. When it is enabled all garnishes, the `Garnish` enum and the `Garnishes`
 //! `Vec` can be serialized and deserialized. This makes it easy to add theme support
-//! to your application.
+//! to your application: a [`Theme`] can be loaded from any format
+//! supported by serde.
+//!
+//! ## Hot reload
+//!
+//! The cargo feature `hot_reload` enables `ThemeWatcher` in the [`theme`] module,
+//! which polls a theme file and reloads it when it changes. It implies `serde`.
 //!
 //! ## Decorated widget
 //!
@@ -249,29 +365,47 @@
 use derive_more::{Deref, DerefMut};
 use ratatui::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Position, Rect, Size},
     style::Style,
     widgets::{StatefulWidget, StatefulWidgetRef, Widget, WidgetRef},
 };
 
+pub mod animation;
 pub mod border;
+mod color;
+mod context;
+pub mod custom;
 #[cfg(feature = "decorated_widget")]
 mod decorator;
+pub mod gradient;
+mod hit;
+mod margin;
 mod padding;
 pub mod shadow;
+pub mod theme;
 pub mod title;
+#[cfg(feature = "serde")]
+mod value;
 
+pub use color::ColorDepth;
+pub use context::{Conditional, ContextFlags, RenderContext};
 #[cfg(feature = "decorated_widget")]
 pub use decorator::{DecoratedStatefulWidget, DecoratedWidget};
-pub use padding::Padding;
+pub use hit::{Hit, HitPart};
+pub use margin::Margin;
+pub use padding::{Padding, RelativeLength, RelativePadding};
 
+use animation::{Frames, MarchingAnts, Pulse};
 use border::{
     CharBorder, CustomBorder, DashedBorder, DoubleBorder, FatInsideBorder, FatOutsideBorder,
     PlainBorder, QuadrantInsideBorder, QuadrantOutsideBorder, RoundedBorder, RoundedDashedBorder,
     ThickBorder, ThickDashedBorder,
 };
-use shadow::{HalfShadow, Shadow};
-use title::{Above, After, Before, Below, Bottom, Left, Right, Title, Top};
+use custom::Custom;
+use gradient::Gradient;
+use shadow::{Bevel, HalfShadow, Shadow, SoftShadow};
+use theme::{Theme, ThemeError};
+use title::{Above, After, Before, Below, Bottom, Left, Right, TextTitle, Title, TitleGroup, Top};
 
 /// A trait that can modify the rendering of a widget.
 pub trait RenderModifier {
@@ -283,6 +417,15 @@ pub trait RenderModifier {
         area
     }
 
+    /// Expands the widget's area, the inverse of `modify_area`.
+    ///
+    /// Returns the area a garnish needs for `modify_area` to return `area`,
+    /// typically enlarged to make room for borders, padding, or shadows.
+    /// Default implementation returns the input area unchanged.
+    fn expand_area(&self, area: Rect) -> Rect {
+        area
+    }
+
     /// Executes before the widget is rendered.
     ///
     /// Used for pre-rendering effects like setting background styles or drawing shadows.
@@ -294,6 +437,58 @@ pub trait RenderModifier {
     /// Used for post-rendering effects like drawing titles over borders.
     /// Default implementation does nothing.
     fn after_render(&self, _area: Rect, _buf: &mut Buffer) {}
+
+    /// Returns the part of the garnish at `position` when rendered in `area`.
+    ///
+    /// Used for mouse support. Returns `None` if the garnish doesn't draw
+    /// or reserve space at `position`. Default implementation returns
+    /// [`HitPart::Area`] for positions in `area` outside of `modify_area`.
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        (area.contains(position) && !self.modify_area(area).contains(position))
+            .then_some(HitPart::Area)
+    }
+
+    /// Modifies the widget's rendering area given the [`RenderContext`].
+    ///
+    /// Default implementation calls `modify_area`.
+    fn modify_area_with(&self, area: Rect, _context: &RenderContext) -> Rect {
+        self.modify_area(area)
+    }
+
+    /// Expands the widget's area given the [`RenderContext`], the inverse
+    /// of `modify_area_with`.
+    ///
+    /// Default implementation calls `expand_area`.
+    fn expand_area_with(&self, area: Rect, _context: &RenderContext) -> Rect {
+        self.expand_area(area)
+    }
+
+    /// Executes before the widget is rendered with the [`RenderContext`].
+    ///
+    /// Default implementation calls `before_render`.
+    fn before_render_with(&self, area: Rect, buf: &mut Buffer, _context: &RenderContext) {
+        self.before_render(area, buf);
+    }
+
+    /// Executes after the widget is rendered with the [`RenderContext`].
+    ///
+    /// Default implementation calls `after_render`.
+    fn after_render_with(&self, area: Rect, buf: &mut Buffer, _context: &RenderContext) {
+        self.after_render(area, buf);
+    }
+
+    /// Returns the part of the garnish at `position` when rendered in `area`
+    /// with the [`RenderContext`].
+    ///
+    /// Default implementation calls `hit_test`.
+    fn hit_test_with(
+        &self,
+        area: Rect,
+        position: Position,
+        _context: &RenderContext,
+    ) -> Option<HitPart> {
+        self.hit_test(area, position)
+    }
 }
 
 nodyn::nodyn! {
@@ -302,23 +497,38 @@ nodyn::nodyn! {
     #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[derive(Debug, Clone)]
     pub enum Garnish<'a> {
+        Bevel,
         CharBorder,
+        Conditional<'a>,
+        Custom,
         CustomBorder,
         DashedBorder,
         DoubleBorder,
         FatInsideBorder,
         FatOutsideBorder,
+        Frames<'a>,
+        Gradient,
         HalfShadow,
+        MarchingAnts,
+        Margin,
         Padding,
         PlainBorder,
+        Pulse,
         QuadrantInsideBorder,
         QuadrantOutsideBorder,
+        RelativePadding,
         RoundedBorder,
         RoundedDashedBorder,
         Shadow,
+        SoftShadow,
+        #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::theme::palette::deserialize_style"))]
         Style,
         ThickBorder,
         ThickDashedBorder,
+        TextTitle<'a, Above>,
+        TextTitle<'a, After>,
+        TextTitle<'a, Before>,
+        TextTitle<'a, Below>,
         Title<'a, Above>,
         Title<'a, After>,
         Title<'a, Before>,
@@ -327,6 +537,14 @@ nodyn::nodyn! {
         Title<'a, Left>,
         Title<'a, Right>,
         Title<'a, Top>,
+        TitleGroup<'a, Above>,
+        TitleGroup<'a, After>,
+        TitleGroup<'a, Before>,
+        TitleGroup<'a, Below>,
+        TitleGroup<'a, Bottom>,
+        TitleGroup<'a, Left>,
+        TitleGroup<'a, Right>,
+        TitleGroup<'a, Top>,
     }
 
     impl is_as;
@@ -334,7 +552,14 @@ nodyn::nodyn! {
     impl RenderModifier {
         fn before_render(&self, area: Rect, buf: &mut Buffer);
         fn modify_area(&self, area: Rect) -> Rect;
+        fn expand_area(&self, area: Rect) -> Rect;
         fn after_render(&self, area: Rect, buf: &mut Buffer);
+        fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart>;
+        fn modify_area_with(&self, area: Rect, context: &RenderContext) -> Rect;
+        fn before_render_with(&self, area: Rect, buf: &mut Buffer, context: &RenderContext);
+        fn expand_area_with(&self, area: Rect, context: &RenderContext) -> Rect;
+        fn after_render_with(&self, area: Rect, buf: &mut Buffer, context: &RenderContext);
+        fn hit_test_with(&self, area: Rect, position: Position, context: &RenderContext) -> Option<HitPart>;
     }
 
     /// A `Vec` of `Garnish` for applying multiple garnishes to widgets.
@@ -437,39 +662,74 @@ impl<W: Widget> From<W> for GarnishedWidget<'_, W> {
     }
 }
 
-impl<W: Widget> Widget for GarnishedWidget<'_, W> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let mut render_area = area;
-        for g in &self.garnishes {
-            g.before_render(render_area, buf);
-            render_area = g.modify_area(render_area);
-        }
+impl<W: Widget> GarnishedWidget<'_, W> {
+    /// Renders the garnished widget, passing `context` to the garnishes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect, style::{Color, Style}, text::Line};
+    /// use ratatui_garnish::{Conditional, GarnishableWidget, RenderContext};
+    ///
+    /// let widget = Line::raw("Test")
+    ///     .garnish(Conditional::when_focused(Style::default().bg(Color::Blue)));
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+    /// let context = RenderContext::new().focused(true);
+    /// widget.render_with_context(buffer.area, &mut buffer, &context);
+    /// assert_eq!(buffer[(0, 0)].bg, Color::Blue);
+    /// ```
+    pub fn render_with_context(self, area: Rect, buf: &mut Buffer, context: &RenderContext) {
+        render_garnished(&self.garnishes, area, buf, context, |area, buf| {
+            self.widget.render(area, buf);
+        });
+    }
+
+    /// Renders the garnished widget at frame `tick` of animated garnishes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Line};
+    /// use ratatui_garnish::{GarnishableWidget, animation::MarchingAnts};
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+    /// for tick in 0..3 {
+    ///     Line::raw("Copied")
+    ///         .garnish(MarchingAnts::default())
+    ///         .render_at(buffer.area, &mut buffer, tick);
+    /// }
+    /// ```
+    pub fn render_at(self, area: Rect, buf: &mut Buffer, tick: u64) {
+        self.render_with_context(area, buf, &RenderContext::new().tick(tick));
+    }
+}
 
-        self.widget.render(render_area, buf);
+impl<W: WidgetRef> GarnishedWidget<'_, W> {
+    /// Renders the garnished widget by reference, passing `context` to
+    /// the garnishes.
+    pub fn render_ref_with_context(&self, area: Rect, buf: &mut Buffer, context: &RenderContext) {
+        render_garnished(&self.garnishes, area, buf, context, |area, buf| {
+            self.widget.render_ref(area, buf);
+        });
+    }
 
-        let mut render_area = area;
-        for g in &self.garnishes {
-            g.after_render(render_area, buf);
-            render_area = g.modify_area(render_area);
-        }
+    /// Renders the garnished widget by reference at frame `tick` of
+    /// animated garnishes.
+    pub fn render_ref_at(&self, area: Rect, buf: &mut Buffer, tick: u64) {
+        self.render_ref_with_context(area, buf, &RenderContext::new().tick(tick));
+    }
+}
+
+impl<W: Widget> Widget for GarnishedWidget<'_, W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_with_context(area, buf, &RenderContext::default());
     }
 }
 
 impl<W: WidgetRef> WidgetRef for GarnishedWidget<'_, W> {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
-        let mut render_area = area;
-        for g in &self.garnishes {
-            g.before_render(render_area, buf);
-            render_area = g.modify_area(render_area);
-        }
-
-        self.widget.render_ref(render_area, buf);
-
-        let mut render_area = area;
-        for g in &self.garnishes {
-            g.after_render(render_area, buf);
-            render_area = g.modify_area(render_area);
-        }
+        self.render_ref_with_context(area, buf, &RenderContext::default());
     }
 }
 
@@ -519,6 +779,48 @@ impl<W: StatefulWidget> From<W> for GarnishedStatefulWidget<'_, W> {
     }
 }
 
+impl<W: StatefulWidget> GarnishedStatefulWidget<'_, W> {
+    /// Renders the garnished widget, passing `context` to the garnishes.
+    pub fn render_with_context(
+        self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut W::State,
+        context: &RenderContext,
+    ) {
+        render_garnished(&self.garnishes, area, buf, context, |area, buf| {
+            self.widget.render(area, buf, state);
+        });
+    }
+
+    /// Renders the garnished widget at frame `tick` of animated garnishes.
+    pub fn render_at(self, area: Rect, buf: &mut Buffer, state: &mut W::State, tick: u64) {
+        self.render_with_context(area, buf, state, &RenderContext::new().tick(tick));
+    }
+}
+
+impl<W: StatefulWidgetRef> GarnishedStatefulWidget<'_, W> {
+    /// Renders the garnished widget by reference, passing `context` to
+    /// the garnishes.
+    pub fn render_ref_with_context(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut W::State,
+        context: &RenderContext,
+    ) {
+        render_garnished(&self.garnishes, area, buf, context, |area, buf| {
+            self.widget.render_ref(area, buf, state);
+        });
+    }
+
+    /// Renders the garnished widget by reference at frame `tick` of
+    /// animated garnishes.
+    pub fn render_ref_at(&self, area: Rect, buf: &mut Buffer, state: &mut W::State, tick: u64) {
+        self.render_ref_with_context(area, buf, state, &RenderContext::new().tick(tick));
+    }
+}
+
 impl<W> StatefulWidget for GarnishedStatefulWidget<'_, W>
 where
     W: StatefulWidget,
@@ -526,19 +828,7 @@ where
     type State = W::State;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let mut render_area = area;
-        for g in &self.garnishes {
-            g.before_render(render_area, buf);
-            render_area = g.modify_area(render_area);
-        }
-
-        self.widget.render(render_area, buf, state);
-
-        let mut render_area = area;
-        for g in &self.garnishes {
-            g.after_render(render_area, buf);
-            render_area = g.modify_area(render_area);
-        }
+        self.render_with_context(area, buf, state, &RenderContext::default());
     }
 }
 
@@ -549,19 +839,237 @@ where
     type State = W::State;
 
     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let mut render_area = area;
-        for g in &self.garnishes {
-            g.before_render(render_area, buf);
-            render_area = g.modify_area(render_area);
-        }
+        self.render_ref_with_context(area, buf, state, &RenderContext::default());
+    }
+}
 
-        self.widget.render_ref(render_area, buf, state);
+/// Renders `garnishes` around the widget rendered by `render`.
+fn render_garnished<F>(
+    garnishes: &[Garnish<'_>],
+    area: Rect,
+    buf: &mut Buffer,
+    context: &RenderContext,
+    render: F,
+) where
+    F: FnOnce(Rect, &mut Buffer),
+{
+    let mut render_area = area;
+    for g in garnishes {
+        g.before_render_with(render_area, buf, context);
+        render_area = g.modify_area_with(render_area, context);
+    }
 
-        let mut render_area = area;
-        for g in &self.garnishes {
-            g.after_render(render_area, buf);
-            render_area = g.modify_area(render_area);
-        }
+    render(render_area, buf);
+
+    let mut render_area = area;
+    for g in garnishes {
+        g.after_render_with(render_area, buf, context);
+        render_area = g.modify_area_with(render_area, context);
+    }
+}
+
+/// Layout queries of garnished widgets and [`Garnishes`].
+///
+/// The queries use the same geometry as rendering does. Implementors only
+/// return their garnishes, all queries are provided.
+pub trait GarnishLayout<'a> {
+    /// Returns the garnishes, in the order they are applied.
+    fn as_garnishes(&self) -> &[Garnish<'a>];
+
+    /// Returns the area the widget is rendered in when the garnished
+    /// widget is rendered in `area`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{layout::Rect, text::Line};
+    /// use ratatui_garnish::{GarnishLayout, GarnishableWidget, Padding};
+    ///
+    /// let widget = Line::raw("Test").garnish(Padding::uniform(1));
+    /// assert_eq!(widget.inner_area(Rect::new(0, 0, 10, 5)), Rect::new(1, 1, 8, 3));
+    /// ```
+    #[must_use]
+    fn inner_area(&self, area: Rect) -> Rect {
+        self.inner_area_with(area, &RenderContext::default())
+    }
+
+    /// Returns the area each garnish receives when the garnished widget is
+    /// rendered in `area`, in the order of the garnishes.
+    fn areas<'s>(&'s self, area: Rect) -> impl Iterator<Item = Rect> + 's
+    where
+        'a: 's,
+    {
+        areas(self.as_garnishes(), area, RenderContext::default())
+    }
+
+    /// Returns the area the garnished widget needs for the widget to be
+    /// rendered in `area`, the inverse of `inner_area`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{layout::{Rect, Size}, text::Line};
+    /// use ratatui_garnish::{GarnishLayout, GarnishableWidget, Padding};
+    ///
+    /// let widget = Line::raw("Test").garnish(Padding::uniform(1));
+    /// assert_eq!(widget.outer_area(Rect::new(1, 1, 8, 3)), Rect::new(0, 0, 10, 5));
+    /// assert_eq!(widget.outer_size(Size::new(8, 3)), Size::new(10, 5));
+    /// ```
+    #[must_use]
+    fn outer_area(&self, area: Rect) -> Rect {
+        self.outer_area_with(area, &RenderContext::default())
+    }
+
+    /// Returns the size the garnished widget needs for the widget to get `size`.
+    #[must_use]
+    fn outer_size(&self, size: Size) -> Size {
+        self.outer_area(Rect::from((Position::ORIGIN, size)))
+            .as_size()
+    }
+
+    /// Returns the garnish or widget at `position` when the garnished
+    /// widget is rendered in `area`.
+    ///
+    /// Garnishes are tested in order and the first one drawing or reserving
+    /// space at `position` is returned. Titles added before a border are
+    /// rendered over it, so they are hit before the border too.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{layout::{Position, Rect}, text::Line};
+    /// use ratatui_garnish::{
+    ///     GarnishLayout, GarnishableWidget, Hit, HitPart, border::PlainBorder,
+    ///     title::{Title, Top},
+    /// };
+    ///
+    /// let widget = Line::raw("Test")
+    ///     .garnish(Title::<Top>::raw("Title").margin(1))
+    ///     .garnish(PlainBorder::default());
+    /// let area = Rect::new(0, 0, 10, 3);
+    ///
+    /// assert_eq!(
+    ///     widget.hit_test(area, Position::new(2, 0)),
+    ///     Some(Hit::Garnish { index: 0, part: HitPart::Span(0) })
+    /// );
+    /// assert!(matches!(
+    ///     widget.hit_test(area, Position::new(8, 0)),
+    ///     Some(Hit::Garnish { index: 1, part: HitPart::Border(_) })
+    /// ));
+    /// ```
+    #[must_use]
+    fn hit_test(&self, area: Rect, position: Position) -> Option<Hit> {
+        self.hit_test_with(area, position, &RenderContext::default())
+    }
+
+    /// Returns the area the widget is rendered in when the garnished widget is
+    /// rendered in `area` with `context`.
+    ///
+    /// Unlike `inner_area`, [`Conditional`] and animated garnishes take
+    /// `context` into account, like they do when rendering with a context.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{layout::Rect, text::Line};
+    /// use ratatui_garnish::{
+    ///     Conditional, GarnishLayout, GarnishableWidget, Padding, RenderContext,
+    /// };
+    ///
+    /// let widget = Line::raw("Test").garnish(Conditional::when_focused(Padding::uniform(1)));
+    /// let area = Rect::new(0, 0, 10, 5);
+    ///
+    /// assert_eq!(widget.inner_area(area), area);
+    /// assert_eq!(
+    ///     widget.inner_area_with(area, &RenderContext::new().focused(true)),
+    ///     Rect::new(1, 1, 8, 3)
+    /// );
+    /// ```
+    #[must_use]
+    fn inner_area_with(&self, area: Rect, context: &RenderContext) -> Rect {
+        self.as_garnishes().iter().fold(area, |area, garnish| {
+            garnish.modify_area_with(area, context)
+        })
+    }
+
+    /// Returns the area each garnish receives when the garnished widget is rendered
+    /// in `area` with `context`, in the order of the garnishes.
+    fn areas_with<'s>(
+        &'s self,
+        area: Rect,
+        context: &RenderContext<'s>,
+    ) -> impl Iterator<Item = Rect> + 's
+    where
+        'a: 's,
+    {
+        areas(self.as_garnishes(), area, *context)
+    }
+
+    /// Returns the area needed for the widget to be rendered in `area`
+    /// with `context`, the inverse of `inner_area_with`.
+    #[must_use]
+    fn outer_area_with(&self, area: Rect, context: &RenderContext) -> Rect {
+        self.as_garnishes()
+            .iter()
+            .rev()
+            .fold(area, |area, garnish| {
+                garnish.expand_area_with(area, context)
+            })
+    }
+
+    /// Returns the garnish or widget at `position` when the garnished widget is
+    /// rendered in `area` with `context`.
+    #[must_use]
+    fn hit_test_with(
+        &self,
+        area: Rect,
+        position: Position,
+        context: &RenderContext,
+    ) -> Option<Hit> {
+        self.areas_with(area, context)
+            .zip(self.as_garnishes())
+            .enumerate()
+            .find_map(|(index, (area, garnish))| {
+                garnish
+                    .hit_test_with(area, position, context)
+                    .map(|part| Hit::Garnish { index, part })
+            })
+            .or_else(|| {
+                self.inner_area_with(area, context)
+                    .contains(position)
+                    .then_some(Hit::Widget)
+            })
+    }
+}
+
+/// Returns the area passed to each of the `garnishes` when rendering in `area`.
+fn areas<'g>(
+    garnishes: &'g [Garnish<'_>],
+    area: Rect,
+    context: RenderContext<'g>,
+) -> impl Iterator<Item = Rect> + 'g {
+    garnishes.iter().scan(area, move |area, garnish| {
+        let current = *area;
+        *area = garnish.modify_area_with(current, &context);
+        Some(current)
+    })
+}
+
+impl<'a, W> GarnishLayout<'a> for GarnishedWidget<'a, W> {
+    fn as_garnishes(&self) -> &[Garnish<'a>] {
+        &self.garnishes
+    }
+}
+
+impl<'a, W> GarnishLayout<'a> for GarnishedStatefulWidget<'a, W> {
+    fn as_garnishes(&self) -> &[Garnish<'a>] {
+        &self.garnishes
+    }
+}
+
+impl<'a> GarnishLayout<'a> for Garnishes<'a> {
+    fn as_garnishes(&self) -> &[Garnish<'a>] {
+        self.as_slice()
     }
 }
 
@@ -588,6 +1096,48 @@ pub trait GarnishableWidget: Widget + Sized {
         }
     }
 
+    /// Applies the garnishes of the `theme` entry `name` to the widget,
+    /// wrapping it in a `GarnishedWidget`.
+    ///
+    /// The widget gets no garnishes if the entry can't be resolved, use
+    /// [`try_garnish_from_theme`](GarnishableWidget::try_garnish_from_theme)
+    /// to handle errors.
+    fn garnish_from_theme<'a>(self, theme: &Theme<'a>, name: &str) -> GarnishedWidget<'a, Self> {
+        GarnishedWidget {
+            widget: self,
+            garnishes: theme.garnishes(name).map(Into::into).unwrap_or_default(),
+        }
+    }
+
+    /// Applies the garnishes of the `theme` entry `name` to the widget,
+    /// wrapping it in a `GarnishedWidget`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ThemeError`] if the entry can't be resolved, see
+    /// [`Theme::garnishes`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::text::Line;
+    /// use ratatui_garnish::{GarnishableWidget, theme::{Theme, ThemeError}};
+    ///
+    /// let result = Line::raw("Hello").try_garnish_from_theme(&Theme::new(), "panel");
+    /// assert_eq!(result.unwrap_err(), ThemeError::Missing("panel".into()));
+    /// ```
+    fn try_garnish_from_theme<'a>(
+        self,
+        theme: &Theme<'a>,
+        name: &str,
+    ) -> Result<GarnishedWidget<'a, Self>, ThemeError> {
+        let garnishes = theme.garnishes(name)?;
+        Ok(GarnishedWidget {
+            widget: self,
+            garnishes: garnishes.into(),
+        })
+    }
+
     /// Applies a garnish to the widget, wrapping it in a `DecoratedWidget`.
     #[cfg(feature = "decorated_widget")]
     fn decorate<R: RenderModifier>(self, garnish: R) -> DecoratedWidget<Self, R> {
@@ -627,6 +1177,42 @@ pub trait GarnishableStatefulWidget: StatefulWidget + Sized {
         }
     }
 
+    /// Applies the garnishes of the `theme` entry `name` to the widget,
+    /// wrapping it in a `GarnishedStatefulWidget`.
+    ///
+    /// The widget gets no garnishes if the entry can't be resolved, use
+    /// [`try_garnish_from_theme`](GarnishableStatefulWidget::try_garnish_from_theme)
+    /// to handle errors.
+    fn garnish_from_theme<'a>(
+        self,
+        theme: &Theme<'a>,
+        name: &str,
+    ) -> GarnishedStatefulWidget<'a, Self> {
+        GarnishedStatefulWidget {
+            widget: self,
+            garnishes: theme.garnishes(name).map(Into::into).unwrap_or_default(),
+        }
+    }
+
+    /// Applies the garnishes of the `theme` entry `name` to the widget,
+    /// wrapping it in a `GarnishedStatefulWidget`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ThemeError`] if the entry can't be resolved, see
+    /// [`Theme::garnishes`].
+    fn try_garnish_from_theme<'a>(
+        self,
+        theme: &Theme<'a>,
+        name: &str,
+    ) -> Result<GarnishedStatefulWidget<'a, Self>, ThemeError> {
+        let garnishes = theme.garnishes(name)?;
+        Ok(GarnishedStatefulWidget {
+            widget: self,
+            garnishes: garnishes.into(),
+        })
+    }
+
     /// Applies a garnish to the widget, wrapping it in a `DecoratedStatefulWidget`.
     #[cfg(feature = "decorated_widget")]
     fn decorate<R: RenderModifier>(self, garnish: R) -> DecoratedStatefulWidget<Self, R> {
@@ -664,6 +1250,105 @@ mod tests {
         assert_eq!(widget.first_padding(), Some(&Padding::uniform(1)));
     }
 
+    #[test]
+    fn garnish_from_theme_reports_errors() {
+        use ratatui::widgets::List;
+        use theme::ThemeEntry;
+
+        let mut theme = Theme::new();
+        theme.insert("panel", vec![Garnish::from(Padding::uniform(1))]);
+        theme.insert("loop", ThemeEntry::default().extends("loop"));
+
+        let widget = Line::raw("Test").try_garnish_from_theme(&theme, "panel");
+        assert_eq!(widget.unwrap().len(), 1);
+        let list =
+            GarnishableStatefulWidget::try_garnish_from_theme(List::new(["a"]), &theme, "loop");
+        assert_eq!(list.unwrap_err(), ThemeError::Cycle("loop".into()));
+
+        // the infallible versions fall back to no garnishes
+        assert!(
+            Line::raw("Test")
+                .garnish_from_theme(&theme, "loop")
+                .is_empty()
+        );
+        let list =
+            GarnishableStatefulWidget::garnish_from_theme(List::new(["a"]), &theme, "missing");
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn inner_area_matches_rendering() {
+        let area = Rect::new(2, 1, 12, 8);
+        let widget = Line::raw("Test")
+            .garnish(Padding::uniform(1))
+            .garnish(border::PlainBorder::default())
+            .garnish(Style::default().bg(Color::Blue));
+
+        assert_eq!(
+            widget.areas(area).collect::<Vec<_>>(),
+            vec![area, Rect::new(3, 2, 10, 6), Rect::new(4, 3, 8, 4)]
+        );
+        assert_eq!(widget.inner_area(area), Rect::new(4, 3, 8, 4));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 10));
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer[(4, 3)].symbol(), "T");
+    }
+
+    #[test]
+    fn outer_area_inverts_inner_area() {
+        let area = Rect::new(3, 2, 30, 12);
+        let widget = Line::raw("Test")
+            .garnish(shadow::Shadow::new(-2, 1))
+            .garnish(shadow::HalfShadow::new(3, -1))
+            .garnish(Title::<Above>::raw("Above"))
+            .garnish(Title::<Below>::raw("Below"))
+            .garnish(Title::<Before>::raw("Before"))
+            .garnish(Title::<After>::raw("After"))
+            .garnish(Title::<Top>::raw("Top"))
+            .garnish(border::PlainBorder::new(
+                border::Borders::LEFT | border::Borders::TOP,
+            ))
+            .garnish(Padding::new(1, 2, 3, 0))
+            .garnish(Style::default().bg(Color::Blue));
+
+        let inner = widget.inner_area(area);
+        assert_eq!(widget.outer_area(inner), area);
+        assert_eq!(widget.outer_size(inner.as_size()), area.as_size());
+    }
+
+    #[test]
+    fn hit_test_finds_garnish_layers() {
+        let area = Rect::new(0, 0, 12, 8);
+        let widget = Line::raw("Test")
+            .garnish(shadow::Shadow::default())
+            .garnish(Title::<Above>::default().spans(["A", "b"]))
+            .garnish(border::PlainBorder::default())
+            .garnish(Padding::uniform(1));
+        let hit = |x, y| widget.hit_test(area, Position::new(x, y));
+        let garnish = |index, part| Some(Hit::Garnish { index, part });
+
+        assert_eq!(hit(11, 3), garnish(0, HitPart::Shadow));
+        assert_eq!(hit(5, 7), garnish(0, HitPart::Shadow));
+        assert_eq!(hit(11, 0), garnish(0, HitPart::Area));
+        assert_eq!(hit(1, 0), garnish(1, HitPart::Span(1)));
+        assert_eq!(hit(5, 0), garnish(1, HitPart::Area));
+        assert_eq!(
+            hit(0, 1),
+            garnish(
+                2,
+                HitPart::Border(border::Borders::TOP | border::Borders::LEFT)
+            )
+        );
+        assert_eq!(
+            hit(5, 6),
+            garnish(2, HitPart::Border(border::Borders::BOTTOM))
+        );
+        assert_eq!(hit(1, 2), garnish(3, HitPart::Area));
+        assert_eq!(hit(4, 4), Some(Hit::Widget));
+        assert_eq!(hit(20, 20), None);
+    }
+
     #[test]
     fn widget_rendering() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
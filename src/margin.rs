@@ -0,0 +1,172 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::Style,
+};
+
+use crate::{HitPart, Padding, RenderModifier};
+
+/// Margin garnish.
+///
+/// Reserves space around the widget like [`Padding`], but clears the
+/// cells it reserves: they are reset, dropping the symbols and styles
+/// earlier garnishes or widgets left in them, so the margin stays
+/// transparent even outside of a styled panel. A symbol and a style to
+/// fill the margin with can be set instead.
+///
+/// Margins are not part of the widget, [`hit_test`](RenderModifier::hit_test)
+/// returns `None` for them.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     style::{Color, Style},
+///     text::Text,
+///     widgets::Widget,
+/// };
+/// use ratatui_garnish::{GarnishableWidget, Margin, Padding, border::PlainBorder};
+///
+/// let widget = Text::raw("")
+///     .garnish(Style::new().bg(Color::Blue))
+///     .garnish(Margin::new(Padding::horizontal(1)))
+///     .garnish(PlainBorder::default());
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+/// widget.render(buffer.area, &mut buffer);
+/// assert_eq!(buffer[(0, 0)].bg, Color::Reset);
+/// assert_eq!(buffer[(1, 0)].bg, Color::Blue);
+/// assert_eq!(buffer[(1, 0)].symbol(), "┌");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Margin {
+    spacing: Padding,
+    symbol: char,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_style")
+    )]
+    style: Style,
+}
+
+impl Margin {
+    /// Creates a `Margin` reserving the space of `spacing` and resetting
+    /// the cells in it.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn new(spacing: Padding) -> Self {
+        Self {
+            spacing,
+            symbol: ' ',
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the symbol the margin is filled with, a space by default.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn symbol(mut self, symbol: char) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Sets the style the margin is filled with.
+    ///
+    /// The style replaces the style of the cells rather than being patched
+    /// onto it, colors and modifiers not set by `style` are reset.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the space reserved around the widget.
+    #[must_use]
+    pub const fn spacing(&self) -> Padding {
+        self.spacing
+    }
+}
+
+impl Default for Margin {
+    /// Creates a `Margin` of 0 on all sides.
+    fn default() -> Self {
+        Self::new(Padding::ZERO)
+    }
+}
+
+impl From<Padding> for Margin {
+    fn from(spacing: Padding) -> Self {
+        Self::new(spacing)
+    }
+}
+
+impl RenderModifier for Margin {
+    fn modify_area(&self, area: Rect) -> Rect {
+        self.spacing.modify_area(area)
+    }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        self.spacing.expand_area(area)
+    }
+
+    fn before_render(&self, area: Rect, buffer: &mut Buffer) {
+        let inner = self.modify_area(area);
+        for position in area.intersection(buffer.area).positions() {
+            if !inner.contains(position) {
+                let cell = &mut buffer[position];
+                cell.reset();
+                cell.set_char(self.symbol).set_style(self.style);
+            }
+        }
+    }
+
+    fn hit_test(&self, _area: Rect, _position: Position) -> Option<HitPart> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Modifier};
+
+    use super::*;
+
+    #[test]
+    fn margin_clears_reserved_cells() {
+        let area = Rect::new(0, 0, 4, 3);
+        let margin = Margin::new(Padding::new(1, 0, 1, 0));
+        assert_eq!(margin.modify_area(area), Rect::new(1, 1, 3, 2));
+        assert_eq!(margin.expand_area(Rect::new(1, 1, 3, 2)), area);
+        assert_eq!(margin.hit_test(area, Position::new(0, 0)), None);
+
+        let mut buffer = Buffer::with_lines(["abcd", "efgh", "ijkl"]);
+        let style = Style::new().fg(Color::Red).bg(Color::Blue);
+        buffer.set_style(area, style.add_modifier(Modifier::BOLD));
+        margin.before_render(area, &mut buffer);
+        assert_eq!(buffer[(0, 0)], ratatui::buffer::Cell::EMPTY);
+        assert_eq!(buffer[(3, 0)], ratatui::buffer::Cell::EMPTY);
+        assert_eq!(buffer[(0, 2)], ratatui::buffer::Cell::EMPTY);
+        assert_eq!(buffer[(1, 1)].symbol(), "f");
+        assert_eq!(buffer[(1, 1)].bg, Color::Blue);
+
+        let margin = margin.symbol('.').style(Style::new().fg(Color::Green));
+        margin.before_render(area, &mut buffer);
+        assert_eq!(buffer[(2, 0)].symbol(), ".");
+        assert_eq!(buffer[(2, 0)].fg, Color::Green);
+        assert_eq!(buffer[(2, 0)].bg, Color::Reset);
+        assert!(buffer[(2, 0)].modifier.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn margin_serialization() {
+        let margin = Margin::new(Padding::symmetric(2, 1))
+            .symbol('·')
+            .style(Style::new().fg(Color::DarkGray));
+        let json = serde_json::to_string(&margin).unwrap();
+        let restored: Margin = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, margin);
+    }
+}
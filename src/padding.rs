@@ -178,6 +178,305 @@ impl RenderModifier for Padding {
             height: area.height.saturating_sub(self.top + self.bottom),
         }
     }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        Rect {
+            x: area.x.saturating_sub(self.left),
+            y: area.y.saturating_sub(self.top),
+            width: area.width.saturating_add(self.left + self.right),
+            height: area.height.saturating_add(self.top + self.bottom),
+        }
+    }
+}
+
+/// A length of a [`RelativePadding`] side, a percentage of the length of
+/// the area clamped to a minimum and a maximum number of cells.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_garnish::RelativeLength;
+///
+/// let length = RelativeLength::percent(10).min(1).max(4);
+/// assert_eq!(length.resolve(5), 1);
+/// assert_eq!(length.resolve(30), 3);
+/// assert_eq!(length.resolve(300), 4);
+/// assert_eq!(RelativeLength::cells(2).resolve(300), 2);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RelativeLength {
+    /// Percentage of the length of the area, from 0 to 100
+    pub percent: u16,
+    /// Minimum number of cells
+    pub min: u16,
+    /// Maximum number of cells
+    pub max: u16,
+}
+
+impl RelativeLength {
+    /// Creates a length of `percent` percent of the length of the area,
+    /// rounded down.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn percent(percent: u16) -> Self {
+        Self {
+            percent,
+            min: 0,
+            max: u16::MAX,
+        }
+    }
+
+    /// Creates a length of a fixed number of cells.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn cells(cells: u16) -> Self {
+        Self {
+            percent: 0,
+            min: cells,
+            max: cells,
+        }
+    }
+
+    /// Sets the minimum number of cells.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn min(mut self, min: u16) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the maximum number of cells. The minimum wins when it is larger.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn max(mut self, max: u16) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Returns the number of cells for an area `length` cells long.
+    #[must_use]
+    pub fn resolve(self, length: u16) -> u16 {
+        let cells = u32::from(length) * u32::from(self.percent.min(100)) / 100;
+        u16::try_from(cells)
+            .unwrap_or(u16::MAX)
+            .min(self.max)
+            .max(self.min)
+    }
+}
+
+impl Default for RelativeLength {
+    /// Creates a length of 0 cells.
+    fn default() -> Self {
+        Self::percent(0)
+    }
+}
+
+impl From<u16> for RelativeLength {
+    fn from(cells: u16) -> Self {
+        Self::cells(cells)
+    }
+}
+
+/// Padding relative to the size of the area.
+///
+/// Where [`Padding`] reserves a fixed number of cells, `RelativePadding`
+/// scales with the area, so a theme looks the same on a narrow and a wide
+/// terminal. Every side is a [`RelativeLength`]: a percentage clamped to
+/// a minimum and a maximum. [`RelativePadding::center`] instead pads the
+/// area to center content of a given size.
+///
+/// [`resolve`](RelativePadding::resolve) returns the `Padding` used for an
+/// area. When the sides take more than the whole area, the content gets
+/// no space. For the same reason [`expand_area`](RenderModifier::expand_area)
+/// returns the area unchanged when the percentages of opposite sides add
+/// up to 100 or more.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use ratatui_garnish::{Padding, RelativeLength, RelativePadding, RenderModifier};
+///
+/// let padding = RelativePadding::horizontal(RelativeLength::percent(10).max(8));
+/// assert_eq!(padding.resolve(Rect::new(0, 0, 40, 10)), Padding::horizontal(4));
+/// assert_eq!(padding.resolve(Rect::new(0, 0, 300, 10)), Padding::horizontal(8));
+///
+/// let dialog = RelativePadding::center(20, 5);
+/// assert_eq!(dialog.modify_area(Rect::new(0, 0, 80, 25)), Rect::new(30, 10, 20, 5));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum RelativePadding {
+    /// A length for every side.
+    Sides {
+        /// Left padding
+        left: RelativeLength,
+        /// Right padding
+        right: RelativeLength,
+        /// Top padding
+        top: RelativeLength,
+        /// Bottom padding
+        bottom: RelativeLength,
+    },
+    /// Centers content of a size, the whole area is used when it is
+    /// smaller.
+    Center {
+        /// Width of the content
+        width: u16,
+        /// Height of the content
+        height: u16,
+    },
+}
+
+impl RelativePadding {
+    /// Creates a new `RelativePadding` by specifying every side individually.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn new(
+        left: RelativeLength,
+        right: RelativeLength,
+        top: RelativeLength,
+        bottom: RelativeLength,
+    ) -> Self {
+        Self::Sides {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    /// Creates a `RelativePadding` with the same length for `left` and `right`.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn horizontal(length: RelativeLength) -> Self {
+        Self::symmetric(length, RelativeLength::cells(0))
+    }
+
+    /// Creates a `RelativePadding` with the same length for `top` and `bottom`.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn vertical(length: RelativeLength) -> Self {
+        Self::symmetric(RelativeLength::cells(0), length)
+    }
+
+    /// Creates a `RelativePadding` with the same length for all sides.
+    ///
+    /// The percentage is taken of the width for `left` and `right` and of
+    /// the height for `top` and `bottom`.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn uniform(length: RelativeLength) -> Self {
+        Self::symmetric(length, length)
+    }
+
+    /// Creates a `RelativePadding` that is symmetric.
+    ///
+    /// The `x` length is used for `left` and `right` and the `y` length is
+    /// used for `top` and `bottom`.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn symmetric(x: RelativeLength, y: RelativeLength) -> Self {
+        Self::new(x, x, y, y)
+    }
+
+    /// Creates a `RelativePadding` centering content of `width` by `height`
+    /// cells.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn center(width: u16, height: u16) -> Self {
+        Self::Center { width, height }
+    }
+
+    /// Returns the padding for `area`.
+    #[must_use]
+    pub fn resolve(&self, area: Rect) -> Padding {
+        match *self {
+            Self::Sides {
+                left,
+                right,
+                top,
+                bottom,
+            } => Padding::new(
+                left.resolve(area.width),
+                right.resolve(area.width),
+                top.resolve(area.height),
+                bottom.resolve(area.height),
+            ),
+            Self::Center { width, height } => {
+                let x = area.width.saturating_sub(width);
+                let y = area.height.saturating_sub(height);
+                Padding::new(x / 2, x - x / 2, y / 2, y - y / 2)
+            }
+        }
+    }
+
+    /// Returns the length of an area leaving `inner` cells between the
+    /// sides `start` and `end`, or `None` when their percentages take the
+    /// whole area. Saturates at `u16::MAX`.
+    fn outer_length(inner: u16, start: RelativeLength, end: RelativeLength) -> Option<u16> {
+        let percent = u32::from(start.percent.min(100)) + u32::from(end.percent.min(100));
+        if percent >= 100 {
+            return None;
+        }
+        // the length the percentages leave `inner` cells of, rounded up
+        let length = (u32::from(inner) * 100).div_ceil(100 - percent);
+        let mut length = u16::try_from(length).unwrap_or(u16::MAX);
+        // correct for the minimum and maximum of the sides, `needed` only
+        // moves in one direction until it settles
+        loop {
+            let needed = inner
+                .saturating_add(start.resolve(length))
+                .saturating_add(end.resolve(length));
+            if needed == length {
+                return Some(length);
+            }
+            length = needed;
+        }
+    }
+}
+
+impl Default for RelativePadding {
+    /// Creates a `RelativePadding` of 0 on all sides.
+    fn default() -> Self {
+        Self::uniform(RelativeLength::default())
+    }
+}
+
+impl From<Padding> for RelativePadding {
+    fn from(padding: Padding) -> Self {
+        Self::new(
+            padding.left.into(),
+            padding.right.into(),
+            padding.top.into(),
+            padding.bottom.into(),
+        )
+    }
+}
+
+impl RenderModifier for RelativePadding {
+    fn modify_area(&self, area: Rect) -> Rect {
+        self.resolve(area).modify_area(area)
+    }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        match *self {
+            Self::Sides {
+                left,
+                right,
+                top,
+                bottom,
+            } => {
+                let (Some(width), Some(height)) = (
+                    Self::outer_length(area.width, left, right),
+                    Self::outer_length(area.height, top, bottom),
+                ) else {
+                    return area;
+                };
+                let padding = self.resolve(Rect::new(0, 0, width, height));
+                Rect {
+                    x: area.x.saturating_sub(padding.left),
+                    y: area.y.saturating_sub(padding.top),
+                    width,
+                    height,
+                }
+            }
+            // content of the size fills the area
+            Self::Center { .. } => area,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -210,6 +509,107 @@ mod tests {
         assert_eq!(Padding::bottom(1), Padding::new(0, 0, 0, 1));
     }
 
+    #[test]
+    fn expand_area() {
+        let padding = Padding::new(1, 2, 3, 4);
+        let area = Rect::new(5, 5, 10, 10);
+
+        assert_eq!(padding.expand_area(area), Rect::new(4, 2, 13, 17));
+        assert_eq!(padding.modify_area(padding.expand_area(area)), area);
+    }
+
+    #[test]
+    fn relative_padding_scales_with_area() {
+        let padding = RelativePadding::new(
+            RelativeLength::percent(10),
+            RelativeLength::percent(10).min(2),
+            RelativeLength::cells(1),
+            RelativeLength::percent(50).max(3),
+        );
+        assert_eq!(
+            padding.resolve(Rect::new(0, 0, 80, 4)),
+            Padding::new(8, 8, 1, 2)
+        );
+        assert_eq!(
+            padding.resolve(Rect::new(0, 0, 10, 20)),
+            Padding::new(1, 2, 1, 3)
+        );
+        assert_eq!(
+            padding.modify_area(Rect::new(5, 5, 10, 20)),
+            Rect::new(6, 6, 7, 16)
+        );
+        assert_eq!(
+            padding.modify_area(Rect::new(0, 0, 2, 1)),
+            Rect::new(0, 1, 0, 0)
+        );
+
+        for area in [Rect::new(5, 5, 7, 16), Rect::new(10, 10, 64, 1)] {
+            let outer = padding.expand_area(area);
+            assert_eq!(padding.modify_area(outer), area);
+        }
+
+        for padding in [
+            RelativePadding::uniform(RelativeLength::percent(40)),
+            RelativePadding::symmetric(
+                RelativeLength::percent(33).min(5),
+                RelativeLength::percent(30).max(7),
+            ),
+            RelativePadding::new(
+                RelativeLength::percent(29).max(2),
+                RelativeLength::percent(50).min(30),
+                RelativeLength::percent(90),
+                RelativeLength::cells(3),
+            ),
+        ] {
+            for length in [0, 1, 2, 3, 7, 100, 655] {
+                let area = Rect::new(10_000, 10_000, length, length);
+                assert_eq!(padding.modify_area(padding.expand_area(area)), area);
+            }
+        }
+
+        // the sides take the whole area whatever its size
+        let half = RelativePadding::horizontal(RelativeLength::percent(50));
+        assert_eq!(
+            half.expand_area(Rect::new(0, 0, 10, 1)),
+            Rect::new(0, 0, 10, 1)
+        );
+
+        let center = RelativePadding::center(4, 2);
+        assert_eq!(
+            center.modify_area(Rect::new(1, 1, 9, 3)),
+            Rect::new(3, 1, 4, 2)
+        );
+        assert_eq!(
+            center.modify_area(Rect::new(0, 0, 3, 1)),
+            Rect::new(0, 0, 3, 1)
+        );
+        assert_eq!(
+            center.expand_area(Rect::new(3, 1, 4, 2)),
+            Rect::new(3, 1, 4, 2)
+        );
+
+        assert_eq!(
+            RelativePadding::from(Padding::new(1, 2, 3, 4)).resolve(Rect::new(0, 0, 50, 50)),
+            Padding::new(1, 2, 3, 4)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn relative_padding_serialization() {
+        for padding in [
+            RelativePadding::symmetric(RelativeLength::percent(5).min(1), RelativeLength::cells(1)),
+            RelativePadding::center(40, 10),
+        ] {
+            let json = serde_json::to_string(&padding).unwrap();
+            let restored: RelativePadding = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored, padding);
+        }
+
+        let length: RelativeLength = serde_json::from_str(r#"{"percent":20}"#).unwrap();
+        assert_eq!(length, RelativeLength::percent(20));
+    }
+
     #[test]
     const fn can_be_const() {
         const _PADDING: Padding = Padding::new(1, 1, 1, 1);
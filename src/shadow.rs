@@ -22,11 +22,57 @@
 //!     .garnish(Shadow::default().dark());
 //! ```
 //!
+//! # Tinted Shadows
+//!
+//! Shade characters replace whatever was rendered behind the widget. A
+//! [`Tint`] keeps the symbols and darkens their colors instead, so a popup
+//! over a busy dashboard doesn't wipe out the dashboard.
+//!
+//! ```rust
+//! use ratatui::{style::Color, text::Text};
+//! use ratatui_garnish::{ColorDepth, GarnishableWidget, shadow::{Shadow, Tint}};
+//!
+//! let widget = Text::raw("Popup")
+//!     .garnish(Shadow::new(2, 1).tint(Tint::new(Color::Black, 60).color_depth(ColorDepth::Indexed)));
+//! ```
+//!
+//! # Shadows Outside the Area
+//!
+//! By default a shadow is drawn inside its area and the widget shrinks to
+//! make room for it. [`ShadowPlacement::Outside`] keeps the full area for
+//! the widget and casts the shadow on the cells around it instead, clipped
+//! to the buffer, which suits modal dialogs floating over other content.
+//!
+//! # `SoftShadow`
+//!
+//! `SoftShadow` blurs the edge of the shadow: it is darkest under the
+//! moved area of the widget and fades out over a spread of cells, which
+//! gives large dialogs more depth than a solid band.
+//!
+//! # `Bevel`
+//!
+//! `Bevel` shades the inside edges of its area instead of casting a
+//! shadow: darker at the top and left and lighter at the bottom and right
+//! for an inset widget like a text input or a pressed button, the reverse
+//! for a raised one. Like [`Padding`] it reserves the edges, the widget is
+//! rendered inside the bevel.
+//!
+//! ```rust
+//! use ratatui::text::Text;
+//! use ratatui_garnish::{GarnishableWidget, shadow::{Bevel, Relief}};
+//!
+//! let input = Text::raw("search…").garnish(Bevel::default());
+//! let button = Text::raw("OK").garnish(Bevel::new(Relief::Raised).half_blocks());
+//! ```
+//!
 //! # `HalfShadow`
 //!
 //! `HalfShadow` provides sub-character precision by specifying offsets
 //! in half-character lengths. It uses various Unicode block characters
-//! to create smooth shadow edges. Only full-opacity shadows are supported.
+//! to create smooth shadow edges. These are solid by default; with a
+//! [`Tint`] the shadow darkens the cells instead. The shaded half of a
+//! block on the edge is drawn in the tinted background color, so the
+//! density and color of the shadow follow the tint.
 //!
 //! # Example
 //!
@@ -43,8 +89,121 @@
 //! let widget = Text::raw("Dialog box")
 //!     .garnish(HalfShadow::new(3, 2));
 //! ```
-use crate::RenderModifier;
-use ratatui::layout::{Position, Rect};
+use crate::{ColorDepth, HitPart, Padding, RenderModifier, color};
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::{Position, Rect},
+    style::{Color, Modifier},
+};
+
+/// Darkens cells by blending their colors with a shadow color, keeping
+/// their symbols.
+///
+/// The default foreground color, `Color::Reset`, has no RGB value and is
+/// dimmed instead. A `Reset` background becomes the shadow color from an
+/// amount of 50 percent.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Cell, style::Color};
+/// use ratatui_garnish::{ColorDepth, shadow::Tint};
+///
+/// let mut cell = Cell::new("x");
+/// cell.set_fg(Color::Rgb(200, 100, 0)).set_bg(Color::LightRed);
+///
+/// Tint::new(Color::Black, 50).apply(&mut cell);
+/// assert_eq!(cell.symbol(), "x");
+/// assert_eq!(cell.fg, Color::Rgb(100, 50, 0));
+///
+/// let mut cell = Cell::new("x");
+/// cell.set_fg(Color::LightRed);
+/// Tint::new(Color::Black, 50).color_depth(ColorDepth::Ansi).apply(&mut cell);
+/// assert_eq!(cell.fg, Color::Red);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Tint {
+    /// The color blended into the cells, defaults to `Color::Black`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_color")
+    )]
+    pub color: Color,
+    /// How much of the color is blended in, from 0 to 100 percent,
+    /// defaults to 50.
+    pub amount: u8,
+    /// The colors available, defaults to `TrueColor`.
+    pub color_depth: ColorDepth,
+}
+
+impl Tint {
+    /// Creates a tint blending in `amount` percent of `color`.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn new(color: Color, amount: u8) -> Self {
+        Self {
+            color,
+            amount,
+            color_depth: ColorDepth::TrueColor,
+        }
+    }
+
+    /// Sets the colors available to the tint.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = color_depth;
+        self
+    }
+
+    /// Darkens the colors of `cell`.
+    pub fn apply(self, cell: &mut Cell) {
+        if cell.fg == Color::Reset {
+            cell.modifier.insert(Modifier::DIM);
+        } else {
+            cell.fg = self.blend(cell.fg);
+        }
+        cell.bg = self.blend(cell.bg);
+    }
+
+    /// Returns `color` darkened by the tint.
+    fn blend(self, color: Color) -> Color {
+        let t = f32::from(self.amount.min(100)) / 100.0;
+        color::interpolate(color, self.color, t, self.color_depth)
+    }
+
+    /// Draws the block character `symbol` in the background of `cell`
+    /// darkened by the tint, so the block shows a tinted part of the cell.
+    ///
+    /// A `Reset` background the tint leaves `Reset` has no color to draw
+    /// the block in, the cell is left as it is.
+    fn paint(self, cell: &mut Cell, symbol: char) {
+        let color = self.blend(cell.bg);
+        if color != Color::Reset {
+            cell.set_char(symbol).set_fg(color);
+        }
+    }
+}
+
+impl Default for Tint {
+    /// Creates a tint blending in 50 percent black.
+    fn default() -> Self {
+        Self::new(Color::Black, 50)
+    }
+}
+
+/// Where a [`Shadow`] is drawn relative to its area.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ShadowPlacement {
+    /// Inside the area, the widget is rendered in the rest of the area.
+    /// The default.
+    #[default]
+    Inside,
+    /// Outside the area, on the cells next to it. The widget is rendered
+    /// in the whole area.
+    Outside,
+}
 
 /// A shadow garnish that renders a drop shadow using Unicode shade characters.
 ///
@@ -58,6 +217,8 @@ pub struct Shadow {
     x_offset: i8,
     y_offset: i8,
     symbol: char,
+    tint: Option<Tint>,
+    placement: ShadowPlacement,
 }
 
 impl Shadow {
@@ -138,6 +299,76 @@ impl Shadow {
         self.symbol = '█';
         self
     }
+
+    /// Darkens the cells the shadow falls on with `tint` instead of drawing
+    /// shade characters over them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect, style::{Color, Style}};
+    /// use ratatui_garnish::{RenderModifier, shadow::{Shadow, Tint}};
+    ///
+    /// let mut buffer = Buffer::with_lines(["abc", "def"]);
+    /// buffer.set_style(buffer.area, Style::new().bg(Color::Rgb(100, 100, 100)));
+    ///
+    /// Shadow::new(1, 1).tint(Tint::new(Color::Black, 50)).before_render(buffer.area, &mut buffer);
+    /// assert_eq!(buffer[(2, 1)].symbol(), "f");
+    /// assert_eq!(buffer[(2, 1)].bg, Color::Rgb(50, 50, 50));
+    /// assert_eq!(buffer[(0, 0)].bg, Color::Rgb(100, 100, 100));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn tint(mut self, tint: Tint) -> Self {
+        self.tint = Some(tint);
+        self
+    }
+
+    /// Sets where the shadow is drawn relative to its area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect};
+    /// use ratatui_garnish::{RenderModifier, shadow::{Shadow, ShadowPlacement}};
+    ///
+    /// let shadow = Shadow::new(2, 1).placement(ShadowPlacement::Outside);
+    /// let area = Rect::new(0, 0, 3, 2);
+    /// assert_eq!(shadow.modify_area(area), area);
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 4));
+    /// shadow.before_render(area, &mut buffer);
+    /// assert_eq!(buffer, Buffer::with_lines(["      ", "   ▒▒ ", "  ▒▒▒ ", "      "]));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn placement(mut self, placement: ShadowPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Darkens the cell at `position` or draws the shade character on it.
+    fn shade(self, buffer: &mut Buffer, position: Position) {
+        match self.tint {
+            Some(tint) => tint.apply(&mut buffer[position]),
+            None => {
+                buffer[position].set_char(self.symbol);
+            }
+        }
+    }
+
+    /// Returns the area covered by a shadow cast outside of `area`,
+    /// including the part behind `area`.
+    fn cast(self, area: Rect) -> Rect {
+        let shift = |start: u16, offset: i8| {
+            let start = i32::from(start) + i32::from(offset);
+            u16::try_from(start.max(0)).unwrap_or(u16::MAX)
+        };
+        let (x, y) = (shift(area.x, self.x_offset), shift(area.y, self.y_offset));
+        let (right, bottom) = (
+            shift(area.right(), self.x_offset),
+            shift(area.bottom(), self.y_offset),
+        );
+        Rect::new(x, y, right - x, bottom - y)
+    }
 }
 
 impl Default for Shadow {
@@ -147,12 +378,17 @@ impl Default for Shadow {
             x_offset: 1,
             y_offset: 1,
             symbol: '▒',
+            tint: None,
+            placement: ShadowPlacement::Inside,
         }
     }
 }
 
 impl RenderModifier for Shadow {
     fn modify_area(&self, area: ratatui::prelude::Rect) -> ratatui::prelude::Rect {
+        if self.placement == ShadowPlacement::Outside {
+            return area;
+        }
         let width = area
             .width
             .saturating_sub(u16::from(self.x_offset.unsigned_abs()));
@@ -184,9 +420,75 @@ impl RenderModifier for Shadow {
         }
     }
 
+    fn expand_area(&self, area: Rect) -> Rect {
+        if self.placement == ShadowPlacement::Outside {
+            return area;
+        }
+        let width = u16::from(self.x_offset.unsigned_abs());
+        let height = u16::from(self.y_offset.unsigned_abs());
+
+        Rect {
+            x: if self.x_offset < 0 {
+                area.x.saturating_sub(width)
+            } else {
+                area.x
+            },
+            y: if self.y_offset < 0 {
+                area.y.saturating_sub(height)
+            } else {
+                area.y
+            },
+            width: area.width.saturating_add(width),
+            height: area.height.saturating_add(height),
+        }
+    }
+
     fn before_render(&self, area: Rect, buffer: &mut ratatui::prelude::Buffer) {
+        if self.placement == ShadowPlacement::Outside {
+            for position in self.cast(area).intersection(buffer.area).positions() {
+                if !area.contains(position) {
+                    self.shade(buffer, position);
+                }
+            }
+            return;
+        }
         let widget_area = self.modify_area(area).intersection(buffer.area);
 
+        // the parts overlap in the corner, which must be tinted only once
+        let [horizontal, vertical] = self.shadow_areas(area, widget_area);
+        let corner = horizontal.intersection(vertical);
+        let positions = horizontal.positions().chain(
+            vertical
+                .positions()
+                .filter(|position| !corner.contains(*position)),
+        );
+        for position in positions {
+            self.shade(buffer, position);
+        }
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        if self.placement == ShadowPlacement::Outside {
+            let shadow = self.cast(area).contains(position) && !area.contains(position);
+            return shadow.then_some(HitPart::Shadow);
+        }
+        let widget_area = self.modify_area(area);
+        if self
+            .shadow_areas(area, widget_area)
+            .iter()
+            .any(|shadow_area| shadow_area.contains(position))
+        {
+            Some(HitPart::Shadow)
+        } else {
+            (area.contains(position) && !widget_area.contains(position)).then_some(HitPart::Area)
+        }
+    }
+}
+
+impl Shadow {
+    /// Returns the horizontal and vertical parts of the shadow for a widget
+    /// rendered in `widget_area`.
+    fn shadow_areas(self, area: Rect, widget_area: Rect) -> [Rect; 2] {
         let (horizontal_x, vertical_x) = if self.x_offset < 0 {
             (area.x, area.x)
         } else {
@@ -205,18 +507,202 @@ impl RenderModifier for Shadow {
             )
         };
 
-        for x in horizontal_x..(horizontal_x + widget_area.width) {
-            for y in horizontal_y..(horizontal_y + u16::from(self.y_offset.unsigned_abs())) {
-                buffer[(x, y)].set_char(self.symbol);
-            }
+        [
+            Rect {
+                x: horizontal_x,
+                y: horizontal_y,
+                width: widget_area.width,
+                height: u16::from(self.y_offset.unsigned_abs()),
+            },
+            Rect {
+                x: vertical_x,
+                y: vertical_y,
+                width: u16::from(self.x_offset.unsigned_abs()),
+                height: widget_area.height,
+            },
+        ]
+    }
+}
+
+/// A shadow fading with the distance from the widget.
+///
+/// The shadow is the area of the widget moved by the offsets and blurred
+/// by `spread` cells on every side: the cells under the moved area are
+/// dark (`▓`), the cells around it get lighter (`▒`, `░`) up to `spread`
+/// cells away. With a [`Tint`] the tint fades instead.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect};
+/// use ratatui_garnish::{RenderModifier, shadow::SoftShadow};
+///
+/// let shadow = SoftShadow::new(2, 1).spread(1);
+/// let area = Rect::new(0, 0, 8, 4);
+/// assert_eq!(shadow.modify_area(area), Rect::new(0, 0, 5, 2));
+///
+/// let mut buffer = Buffer::empty(area);
+/// shadow.before_render(area, &mut buffer);
+/// assert_eq!(
+///     buffer,
+///     Buffer::with_lines(["     ▒▒▒", "     ▓▓▒", " ▒▓▓▓▓▓▒", " ▒▒▒▒▒▒▒"])
+/// );
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SoftShadow {
+    x_offset: i8,
+    y_offset: i8,
+    spread: u8,
+    tint: Option<Tint>,
+    placement: ShadowPlacement,
+}
+
+impl SoftShadow {
+    /// Shade characters from light to dark.
+    const SHADES: [char; 3] = ['░', '▒', '▓'];
+
+    /// Creates a soft shadow with the given offsets and a spread of 2.
+    ///
+    /// Offsets are in full character lengths, positive values shift the
+    /// shadow right and down.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn new(x_offset: i8, y_offset: i8) -> Self {
+        Self {
+            x_offset,
+            y_offset,
+            spread: 2,
+            tint: None,
+            placement: ShadowPlacement::Inside,
         }
+    }
+
+    /// Sets the number of cells the shadow fades over.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn spread(mut self, spread: u8) -> Self {
+        self.spread = spread;
+        self
+    }
 
-        for x in vertical_x..(vertical_x + u16::from(self.x_offset.unsigned_abs())) {
-            for y in vertical_y..(vertical_y + widget_area.height) {
-                buffer[(x, y)].set_char(self.symbol);
+    /// Darkens the cells the shadow falls on with `tint`, fading with the
+    /// distance, instead of drawing shade characters over them.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn tint(mut self, tint: Tint) -> Self {
+        self.tint = Some(tint);
+        self
+    }
+
+    /// Sets where the shadow is drawn relative to its area.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn placement(mut self, placement: ShadowPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Returns the space the shadow takes on every side of the widget.
+    fn extent(self) -> Padding {
+        let spread = i16::from(self.spread);
+        let (x_offset, y_offset) = (i16::from(self.x_offset), i16::from(self.y_offset));
+        let side = |offset: i16| u16::try_from(spread + offset).unwrap_or_default();
+        Padding::new(
+            side(-x_offset),
+            side(x_offset),
+            side(-y_offset),
+            side(y_offset),
+        )
+    }
+
+    /// Returns the density of the shadow at `position`, from 1 at `spread`
+    /// cells from the moved `widget_area` to `spread + 1` under it, or
+    /// `None` outside the shadow.
+    fn density(self, widget_area: Rect, position: Position) -> Option<u16> {
+        let distance = |position: u16, start: u16, length: u16, offset: i8| {
+            let start = i32::from(start) + i32::from(offset);
+            let end = start + i32::from(length) - 1;
+            let position = i32::from(position);
+            (start - position).max(position - end).max(0)
+        };
+        let distance = distance(position.x, widget_area.x, widget_area.width, self.x_offset).max(
+            distance(position.y, widget_area.y, widget_area.height, self.y_offset),
+        );
+        let density = i32::from(self.spread) + 1 - distance;
+        u16::try_from(density).ok().filter(|&density| density > 0)
+    }
+
+    /// Returns the area the shadow is drawn in for a widget rendered in
+    /// `widget_area`.
+    fn shadow_area(self, area: Rect, widget_area: Rect) -> Rect {
+        match self.placement {
+            ShadowPlacement::Inside => area,
+            ShadowPlacement::Outside => self.extent().expand_area(widget_area),
+        }
+    }
+}
+
+impl Default for SoftShadow {
+    /// Creates a `SoftShadow` with offsets of 1, 1 and a spread of 2.
+    fn default() -> Self {
+        Self::new(1, 1)
+    }
+}
+
+impl RenderModifier for SoftShadow {
+    fn modify_area(&self, area: Rect) -> Rect {
+        match self.placement {
+            ShadowPlacement::Inside => self.extent().modify_area(area),
+            ShadowPlacement::Outside => area,
+        }
+    }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        match self.placement {
+            ShadowPlacement::Inside => self.extent().expand_area(area),
+            ShadowPlacement::Outside => area,
+        }
+    }
+
+    fn before_render(&self, area: Rect, buffer: &mut Buffer) {
+        let widget_area = self.modify_area(area);
+        if widget_area.is_empty() {
+            return;
+        }
+        let levels = u32::from(self.spread) + 1;
+        let shadow_area = self.shadow_area(area, widget_area);
+        for position in shadow_area.intersection(buffer.area).positions() {
+            if widget_area.contains(position) {
+                continue;
+            }
+            let Some(density) = self.density(widget_area, position) else {
+                continue;
+            };
+            let density = u32::from(density);
+            let cell = &mut buffer[position];
+            if let Some(tint) = self.tint {
+                let amount = u32::from(tint.amount) * density / levels;
+                let amount = u8::try_from(amount).unwrap_or(u8::MAX);
+                Tint { amount, ..tint }.apply(cell);
+            } else {
+                let shade = usize::try_from((density * 3).div_ceil(levels)).unwrap_or(1);
+                cell.set_char(Self::SHADES[shade - 1]);
             }
         }
     }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        let widget_area = self.modify_area(area);
+        if widget_area.contains(position) {
+            return None;
+        }
+        let shadow = self.shadow_area(area, widget_area).contains(position)
+            && self.density(widget_area, position).is_some();
+        if shadow {
+            Some(HitPart::Shadow)
+        } else {
+            let reserved = self.placement == ShadowPlacement::Inside && area.contains(position);
+            reserved.then_some(HitPart::Area)
+        }
+    }
 }
 
 /// Renders a shadow with sub-character precision using half-character offsets.
@@ -230,6 +716,7 @@ impl RenderModifier for Shadow {
 pub struct HalfShadow {
     x_offset: i8,
     y_offset: i8,
+    tint: Option<Tint>,
 }
 
 impl HalfShadow {
@@ -247,17 +734,57 @@ impl HalfShadow {
     /// ```
     #[must_use = "method returns a new instance"]
     pub const fn new(x_offset: i8, y_offset: i8) -> Self {
-        Self { x_offset, y_offset }
+        Self {
+            x_offset,
+            y_offset,
+            tint: None,
+        }
+    }
+
+    /// Darkens the cells the shadow falls on with `tint` instead of
+    /// drawing solid blocks over them.
+    ///
+    /// Cells covered entirely keep their symbols. Cells on the edge get a
+    /// block character colored with their background darkened by the tint,
+    /// the other half keeps the original background. On the default
+    /// background, which a tint below 50 percent leaves untouched, edge
+    /// cells are left as they are.
+    ///
+    /// The density of the shadow comes from [`Tint::amount`]: a lower
+    /// amount gives a lighter shadow. Without a tint the shadow is drawn
+    /// in solid blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect, style::{Color, Style}};
+    /// use ratatui_garnish::{RenderModifier, shadow::{HalfShadow, Tint}};
+    ///
+    /// let mut buffer = Buffer::with_lines(["abcd", "efgh", "ijkl", "mnop"]);
+    /// buffer.set_style(buffer.area, Style::new().bg(Color::Rgb(100, 100, 100)));
+    ///
+    /// let shadow = HalfShadow::new(3, 3).tint(Tint::new(Color::Black, 40));
+    /// shadow.before_render(buffer.area, &mut buffer);
+    ///
+    /// // the edge of the shadow, half covered
+    /// assert_eq!(buffer[(3, 2)].symbol(), "▌");
+    /// assert_eq!(buffer[(3, 2)].fg, Color::Rgb(60, 60, 60));
+    /// assert_eq!(buffer[(3, 2)].bg, Color::Rgb(100, 100, 100));
+    /// // fully covered
+    /// assert_eq!(buffer[(2, 2)].symbol(), "k");
+    /// assert_eq!(buffer[(2, 2)].bg, Color::Rgb(60, 60, 60));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn tint(mut self, tint: Tint) -> Self {
+        self.tint = Some(tint);
+        self
     }
 }
 
 impl Default for HalfShadow {
     /// Creates a `HalfShadow` with offsets of 1, 1 (0.5 characters each).
     fn default() -> Self {
-        Self {
-            x_offset: 1,
-            y_offset: 1,
-        }
+        Self::new(1, 1)
     }
 }
 
@@ -289,33 +816,38 @@ impl RenderModifier for HalfShadow {
         }
     }
 
-    fn before_render(&self, area: Rect, buffer: &mut ratatui::prelude::Buffer) {
-        let widget_area = self.modify_area(area).intersection(buffer.area);
-
-        let delta_x = i32::from(self.x_offset.unsigned_abs() % 2);
-        let delta_y = i32::from(self.y_offset.unsigned_abs() % 2);
-
-        let mut start_x = i32::from(widget_area.left()) + i32::from(self.x_offset / 2);
-        if self.x_offset < 0 {
-            start_x -= 1;
-        }
+    fn expand_area(&self, area: Rect) -> Rect {
+        let x = self.x_offset / 2 + self.x_offset % 2;
+        let y = self.y_offset / 2 + self.y_offset % 2;
+        let width = u16::from(x.unsigned_abs());
+        let height = u16::from(y.unsigned_abs());
 
-        let mut start_y = i32::from(widget_area.top()) + i32::from(self.y_offset / 2);
-        if self.y_offset < 0 {
-            start_y -= 1;
+        Rect {
+            x: if x < 0 {
+                area.x.saturating_sub(width)
+            } else {
+                area.x
+            },
+            y: if y < 0 {
+                area.y.saturating_sub(height)
+            } else {
+                area.y
+            },
+            width: area.width.saturating_add(width),
+            height: area.height.saturating_add(height),
         }
+    }
 
-        let end_x = start_x + i32::from(widget_area.width) + delta_x;
-        let end_y = start_y + i32::from(widget_area.height) + delta_y;
+    fn before_render(&self, area: Rect, buffer: &mut ratatui::prelude::Buffer) {
+        let widget_area = self.modify_area(area).intersection(buffer.area);
 
-        if start_x < 0 || start_y < 0 {
+        let Some(shadow_area) = self.shadow_area(widget_area) else {
             return;
-        }
-
-        let start_x = u16::try_from(start_x).unwrap_or(u16::MAX);
-        let start_y = u16::try_from(start_y).unwrap_or(u16::MAX);
-        let end_x = u16::try_from(end_x).unwrap_or(u16::MAX);
-        let end_y = u16::try_from(end_y).unwrap_or(u16::MAX);
+        };
+        let (start_x, start_y) = (shadow_area.left(), shadow_area.top());
+        let (end_x, end_y) = (shadow_area.right(), shadow_area.bottom());
+        let delta_x = self.x_offset % 2;
+        let delta_y = self.y_offset % 2;
 
         for y in start_y..end_y {
             for x in start_x..end_x {
@@ -342,32 +874,445 @@ impl RenderModifier for HalfShadow {
                     _ => '█',
                 };
 
-                buffer[(x, y)].set_char(symbol);
+                self.shade(&mut buffer[(x, y)], symbol);
+            }
+        }
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        let widget_area = self.modify_area(area);
+        if widget_area.contains(position) {
+            None
+        } else if self
+            .shadow_area(widget_area)
+            .is_some_and(|shadow_area| shadow_area.contains(position))
+        {
+            Some(HitPart::Shadow)
+        } else {
+            area.contains(position).then_some(HitPart::Area)
+        }
+    }
+}
+
+impl HalfShadow {
+    /// Draws the part of the shadow covering `cell`, shaped like `symbol`.
+    fn shade(self, cell: &mut Cell, symbol: char) {
+        match self.tint {
+            None => {
+                cell.set_char(symbol);
+            }
+            Some(tint) if symbol == '█' => tint.apply(cell),
+            Some(tint) => tint.paint(cell, symbol),
+        }
+    }
+
+    /// Returns the area covered by the shadow, including the part behind
+    /// the widget rendered in `widget_area`.
+    fn shadow_area(self, widget_area: Rect) -> Option<Rect> {
+        let delta_x = i32::from(self.x_offset.unsigned_abs() % 2);
+        let delta_y = i32::from(self.y_offset.unsigned_abs() % 2);
+
+        let mut start_x = i32::from(widget_area.left()) + i32::from(self.x_offset / 2);
+        if self.x_offset < 0 {
+            start_x -= 1;
+        }
+
+        let mut start_y = i32::from(widget_area.top()) + i32::from(self.y_offset / 2);
+        if self.y_offset < 0 {
+            start_y -= 1;
+        }
+
+        let end_x = start_x + i32::from(widget_area.width) + delta_x;
+        let end_y = start_y + i32::from(widget_area.height) + delta_y;
+
+        if start_x < 0 || start_y < 0 {
+            return None;
+        }
+
+        let start_x = u16::try_from(start_x).unwrap_or(u16::MAX);
+        let start_y = u16::try_from(start_y).unwrap_or(u16::MAX);
+        let end_x = u16::try_from(end_x).unwrap_or(u16::MAX);
+        let end_y = u16::try_from(end_y).unwrap_or(u16::MAX);
+
+        Some(Rect {
+            x: start_x,
+            y: start_y,
+            width: end_x - start_x,
+            height: end_y - start_y,
+        })
+    }
+}
+
+/// The direction a [`Bevel`] appears to go.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Relief {
+    /// Pressed into the surface, darker at the top and left. The default.
+    #[default]
+    Inset,
+    /// Standing out of the surface, lighter at the top and left.
+    Raised,
+}
+
+/// Shades the inside edges of an area so the widget looks inset or raised.
+///
+/// The edges are one cell wide and reserved like a [`Padding`] of 1, the
+/// widget is rendered inside them. The top and left edges are darkened
+/// with the shade tint, the bottom and right edges lightened with the
+/// highlight tint, or the other way around for [`Relief::Raised`]. The
+/// corners at the top right and bottom left belong to the bottom and
+/// right edges.
+///
+/// By default the edges are tinted cells. With
+/// [`half_blocks`](Bevel::half_blocks) they are half blocks on the outer
+/// half of the edge cells, which gives thinner lines.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect, style::Color};
+/// use ratatui_garnish::{RenderModifier, shadow::{Bevel, Relief}};
+///
+/// let bevel = Bevel::new(Relief::Raised).half_blocks();
+/// let area = Rect::new(0, 0, 5, 3);
+/// assert_eq!(bevel.modify_area(area), Rect::new(1, 1, 3, 1));
+///
+/// let mut buffer = Buffer::empty(area);
+/// bevel.before_render(area, &mut buffer);
+/// assert_eq!(buffer[(0, 0)].symbol(), "▛");
+/// assert_eq!(buffer[(4, 0)].symbol(), "▐");
+/// assert_eq!(buffer[(2, 2)].symbol(), "▄");
+/// assert_eq!(buffer[(0, 0)].fg, Color::White);
+/// assert_eq!(buffer[(4, 2)].fg, Color::Black);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Bevel {
+    relief: Relief,
+    shade: Tint,
+    highlight: Tint,
+    half_blocks: bool,
+}
+
+impl Bevel {
+    /// Creates a bevel with the given relief, darkening with 50 percent
+    /// black and lightening with 50 percent white.
+    #[must_use = "constructor returns a new instance"]
+    pub const fn new(relief: Relief) -> Self {
+        Self {
+            relief,
+            shade: Tint::new(Color::Black, 50),
+            highlight: Tint::new(Color::White, 50),
+            half_blocks: false,
+        }
+    }
+
+    /// Sets the tint of the darker edges.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn shade(mut self, shade: Tint) -> Self {
+        self.shade = shade;
+        self
+    }
+
+    /// Sets the tint of the lighter edges.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn highlight(mut self, highlight: Tint) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Draws the edges with half blocks instead of tinting whole cells.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn half_blocks(mut self) -> Self {
+        self.half_blocks = true;
+        self
+    }
+
+    /// Returns the tints of the top and left edges and of the bottom and
+    /// right edges.
+    const fn tints(self) -> (Tint, Tint) {
+        match self.relief {
+            Relief::Inset => (self.shade, self.highlight),
+            Relief::Raised => (self.highlight, self.shade),
+        }
+    }
+}
+
+impl Default for Bevel {
+    /// Creates an inset `Bevel`.
+    fn default() -> Self {
+        Self::new(Relief::Inset)
+    }
+}
+
+impl RenderModifier for Bevel {
+    fn modify_area(&self, area: Rect) -> Rect {
+        Padding::uniform(1).modify_area(area)
+    }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        Padding::uniform(1).expand_area(area)
+    }
+
+    fn before_render(&self, area: Rect, buffer: &mut Buffer) {
+        let inner = self.modify_area(area);
+        let (top_left, bottom_right) = self.tints();
+        for position in area.intersection(buffer.area).positions() {
+            if inner.contains(position) {
+                continue;
+            }
+            let top = position.y == area.top();
+            let left = position.x == area.left();
+            let bottom = position.y == area.bottom() - 1;
+            let right = position.x == area.right() - 1;
+            let (tint, symbol) = match (top, left, bottom, right) {
+                (_, _, true, true) => (bottom_right, '▟'),
+                (_, _, _, true) => (bottom_right, '▐'),
+                (_, _, true, _) => (bottom_right, '▄'),
+                (true, true, _, _) => (top_left, '▛'),
+                (true, _, _, _) => (top_left, '▀'),
+                _ => (top_left, '▌'),
+            };
+            let cell = &mut buffer[position];
+            if self.half_blocks {
+                tint.paint(cell, symbol);
+            } else {
+                tint.apply(cell);
             }
         }
     }
 }
 
-#[cfg(feature = "serde")]
 #[cfg(test)]
 mod tests {
+    use ratatui::style::Style;
+
     use super::*;
 
     #[test]
+    fn tinted_shadow_keeps_symbols() {
+        let mut buffer = Buffer::with_lines(["abc", "def", "ghi"]);
+        buffer[(2, 2)].set_fg(Color::Rgb(200, 100, 0));
+        buffer[(1, 2)].set_bg(Color::Rgb(100, 100, 100));
+
+        let shadow = Shadow::new(1, 1).tint(Tint::new(Color::Black, 50));
+        shadow.before_render(buffer.area, &mut buffer);
+
+        let expected = Buffer::with_lines(["abc", "def", "ghi"]);
+        for position in buffer.area.positions() {
+            assert_eq!(buffer[position].symbol(), expected[position].symbol());
+        }
+        assert_eq!(buffer[(2, 2)].fg, Color::Rgb(100, 50, 0));
+        assert_eq!(buffer[(2, 2)].bg, Color::Black);
+        assert_eq!(buffer[(1, 2)].bg, Color::Rgb(50, 50, 50));
+        assert!(buffer[(1, 2)].modifier.contains(Modifier::DIM));
+        assert_eq!(buffer[(1, 1)], expected[(1, 1)]);
+
+        let mut buffer = Buffer::with_lines(["abc", "def", "ghi"]);
+        Shadow::new(1, 1)
+            .tint(Tint::new(Color::Black, 20))
+            .before_render(buffer.area, &mut buffer);
+        assert_eq!(buffer[(2, 2)].bg, Color::Reset);
+    }
+
+    #[test]
+    fn outside_shadow_keeps_area() {
+        let area = Rect::new(1, 1, 3, 2);
+        let shadow = Shadow::new(2, 1).placement(ShadowPlacement::Outside);
+        assert_eq!(shadow.modify_area(area), area);
+        assert_eq!(shadow.expand_area(area), area);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 4));
+        shadow.before_render(area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["     ", "     ", "    ▒", "   ▒▒"])
+        );
+        assert_eq!(
+            shadow.hit_test(area, Position::new(5, 3)),
+            Some(HitPart::Shadow)
+        );
+        assert_eq!(shadow.hit_test(area, Position::new(3, 2)), None);
+        assert_eq!(shadow.hit_test(area, Position::new(0, 0)), None);
+
+        // clipped at the top left of the buffer
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        let shadow = Shadow::new(-2, -1).placement(ShadowPlacement::Outside);
+        shadow.before_render(Rect::new(1, 0, 2, 2), &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["▒  ", "   ", "   "]));
+        shadow.before_render(Rect::new(1, 1, 2, 2), &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["▒  ", "▒  ", "   "]));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
     fn shadow_serialization() {
         let shadow = Shadow::default();
         let json = serde_json::to_string_pretty(&shadow).unwrap();
 
         let restored: Shadow = serde_json::from_str(&json).unwrap();
         assert_eq!(restored, shadow);
+
+        let tinted = Shadow::new(2, 1)
+            .tint(Tint::new(Color::Blue, 30).color_depth(ColorDepth::Ansi))
+            .placement(ShadowPlacement::Outside);
+        let json = serde_json::to_string(&tinted).unwrap();
+        let restored: Shadow = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, tinted);
+    }
+
+    #[test]
+    fn soft_shadow_fades() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        buffer.set_style(buffer.area, Style::new().bg(Color::Rgb(200, 200, 200)));
+        let area = Rect::new(2, 1, 2, 1);
+        let shadow = SoftShadow::new(1, 0)
+            .spread(1)
+            .tint(Tint::new(Color::Black, 100))
+            .placement(ShadowPlacement::Outside);
+        assert_eq!(shadow.modify_area(area), area);
+
+        shadow.before_render(area, &mut buffer);
+        assert_eq!(buffer[(4, 1)].bg, Color::Rgb(0, 0, 0));
+        assert_eq!(buffer[(2, 0)].bg, Color::Rgb(100, 100, 100));
+        assert_eq!(buffer[(5, 1)].bg, Color::Rgb(100, 100, 100));
+        assert_eq!(buffer[(2, 1)].bg, Color::Rgb(200, 200, 200));
+        assert_eq!(buffer[(1, 1)].bg, Color::Rgb(200, 200, 200));
+        assert_eq!(
+            shadow.hit_test(area, Position::new(5, 1)),
+            Some(HitPart::Shadow)
+        );
+        assert_eq!(shadow.hit_test(area, Position::new(1, 1)), None);
+
+        let area = Rect::new(0, 0, 8, 4);
+        let shadow = SoftShadow::new(2, 1).spread(1);
+        assert_eq!(shadow.expand_area(shadow.modify_area(area)), area);
+        assert_eq!(
+            shadow.hit_test(area, Position::new(1, 2)),
+            Some(HitPart::Shadow)
+        );
+        assert_eq!(
+            shadow.hit_test(area, Position::new(0, 2)),
+            Some(HitPart::Area)
+        );
+        assert_eq!(shadow.hit_test(area, Position::new(2, 0)), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn soft_shadow_serialization() {
+        let shadow = SoftShadow::new(-1, 2)
+            .spread(3)
+            .tint(Tint::default())
+            .placement(ShadowPlacement::Outside);
+        let json = serde_json::to_string(&shadow).unwrap();
+        let restored: SoftShadow = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, shadow);
     }
 
     #[test]
+    fn tinted_half_shadow_colors_blocks() {
+        let mut buffer = Buffer::with_lines(["abc", "def", "ghi"]);
+        let shadow = HalfShadow::new(1, 1).tint(Tint::new(Color::Blue, 60));
+        shadow.before_render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["ab▖", "de▌", "▝▀▘"]);
+        for position in [(2, 0), (2, 1), (0, 2), (1, 2), (2, 2)] {
+            expected[position].set_fg(Color::Blue);
+        }
+        assert_eq!(buffer, expected);
+
+        // a light tint leaves the default background, and the edges on it,
+        // untouched
+        let unshaded = Buffer::with_lines(["abc", "def", "ghi"]);
+        let mut buffer = unshaded.clone();
+        let shadow = HalfShadow::new(1, 1).tint(Tint::new(Color::Blue, 20));
+        shadow.before_render(buffer.area, &mut buffer);
+        assert_eq!(buffer[(1, 2)], unshaded[(1, 2)]);
+        assert_eq!(buffer[(2, 0)], unshaded[(2, 0)]);
+        assert_eq!(buffer[(1, 1)], unshaded[(1, 1)]);
+
+        // on a colored background the edges are tinted
+        let mut buffer = unshaded.clone();
+        buffer.set_style(buffer.area, Style::new().bg(Color::Rgb(100, 100, 100)));
+        shadow.before_render(buffer.area, &mut buffer);
+        assert_eq!(buffer[(1, 2)].symbol(), "▀");
+        assert_eq!(buffer[(1, 2)].fg, Color::Rgb(80, 80, 106));
+    }
+
+    #[test]
+    fn bevel_shades_edges() {
+        let area = Rect::new(1, 1, 4, 3);
+        let bevel = Bevel::default();
+        assert_eq!(bevel.modify_area(area), Rect::new(2, 2, 2, 1));
+        assert_eq!(bevel.expand_area(Rect::new(2, 2, 2, 1)), area);
+        assert_eq!(
+            bevel.hit_test(area, Position::new(1, 1)),
+            Some(HitPart::Area)
+        );
+        assert_eq!(bevel.hit_test(area, Position::new(2, 2)), None);
+
+        let mut buffer = Buffer::with_lines(["abcdef", "ghijkl", "mnopqr", "stuvwx", "yz0123"]);
+        buffer.set_style(buffer.area, Style::new().bg(Color::Rgb(100, 100, 100)));
+        let expected = buffer.clone();
+        bevel.before_render(area, &mut buffer);
+
+        let dark = Color::Rgb(50, 50, 50);
+        let light = Color::Rgb(178, 178, 178);
+        for (x, y, bg) in [
+            (1, 1, dark),
+            (3, 1, dark),
+            (1, 2, dark),
+            (4, 1, light),
+            (4, 2, light),
+            (1, 3, light),
+            (4, 3, light),
+        ] {
+            assert_eq!(buffer[(x, y)].symbol(), expected[(x, y)].symbol());
+            assert_eq!(buffer[(x, y)].bg, bg, "at {x}, {y}");
+        }
+        for position in [(2, 2), (3, 2), (0, 0), (5, 4)] {
+            assert_eq!(buffer[position], expected[position]);
+        }
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Bevel::new(Relief::Raised)
+            .half_blocks()
+            .before_render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["▛▀▀▐", "▌  ▐", "▄▄▄▟"]);
+        for (x, y) in [(0, 0), (1, 0), (2, 0), (0, 1)] {
+            expected[(x, y)].set_fg(Color::White);
+        }
+        for (x, y) in [(3, 0), (3, 1), (0, 2), (1, 2), (2, 2), (3, 2)] {
+            expected[(x, y)].set_fg(Color::Black);
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn bevel_serialization() {
+        let bevel = Bevel::new(Relief::Raised)
+            .shade(Tint::new(Color::Blue, 70))
+            .half_blocks();
+        let json = serde_json::to_string(&bevel).unwrap();
+        let restored: Bevel = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, bevel);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
     fn half_shadow_serialization() {
         let shadow = HalfShadow::default();
         let json = serde_json::to_string_pretty(&shadow).unwrap();
 
         let restored: HalfShadow = serde_json::from_str(&json).unwrap();
         assert_eq!(restored, shadow);
+
+        let tinted = HalfShadow::new(3, -1).tint(Tint::new(Color::Blue, 25));
+        let json = serde_json::to_string(&tinted).unwrap();
+        let restored: HalfShadow = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, tinted);
     }
 }
@@ -0,0 +1,533 @@
+//! Named sets of garnishes.
+//!
+//! A [`Theme`] maps names, like `"panel"` or `"popup.focused"`, to
+//! [`Garnishes`]. An entry can extend another entry: its garnishes are
+//! added after the garnishes of the entry it extends.
+//!
+//! Apply garnishes from a theme with `garnish_from_theme`:
+//!
+//! ```rust
+//! use ratatui::{style::{Color, Style}, text::Line};
+//! use ratatui_garnish::{
+//!     GarnishableWidget, Padding, border::PlainBorder, garnishes,
+//!     theme::{Theme, ThemeEntry},
+//! };
+//!
+//! let mut theme = Theme::new();
+//! theme.insert("panel", garnishes![PlainBorder::default(), Padding::horizontal(1)]);
+//! theme.insert(
+//!     "panel.focused",
+//!     ThemeEntry::new(garnishes![Style::default().fg(Color::Yellow)]).extends("panel"),
+//! );
+//!
+//! let widget = Line::raw("Hello").garnish_from_theme(&theme, "panel.focused");
+//! assert_eq!(widget.len(), 3);
+//! ```
+//!
+//! # Theme Files
+//!
+//! With the `serde` feature enabled a `Theme` can be loaded from any format
+//! supported by serde, like TOML, JSON or RON. Users can then restyle an
+//! application without recompiling it. In TOML a theme looks like:
+//!
+//! ```toml
+//! [panel]
+//! garnishes = [
+//!     { PlainBorder = "ALL" },
+//!     { Padding = { left = 1, right = 1 } },
+//! ]
+//!
+//! ["panel.focused"]
+//! extends = "panel"
+//! garnishes = [{ Style = { fg = "Yellow", add_modifier = "BOLD", sub_modifier = "" } }]
+//! ```
+//!
+//! # Palettes
+//!
+//! Instead of hard-coding colors, serialized garnishes can reference colors
+//! of a `Palette` as `"$name"`. A theme file can define its palette in a
+//! `$palette` table, which must come before the entries. Entry names
+//! starting with `$` are reserved, a theme file with such an entry fails to
+//! load.
+//!
+//! ```toml
+//! ["$palette"]
+//! accent = "Yellow"
+//! border = "#5f87af"
+//!
+//! ["panel.focused"]
+//! garnishes = [{ Style = { fg = "$accent", add_modifier = "", sub_modifier = "" } }]
+//! ```
+//!
+//! Load a theme with `Theme::deserialize_with_palette` to override colors
+//! of the file's palette, e.g. to switch between a dark and a light palette.
+//!
+//! # Hot Reload
+//!
+//! With the `hot_reload` feature a `ThemeWatcher` reloads a theme file when
+//! it changes, so themes can be tweaked while the application runs.
+use std::collections::BTreeMap;
+
+use crate::{Garnish, Garnishes};
+
+#[cfg(feature = "serde")]
+pub(crate) mod palette;
+#[cfg(feature = "hot_reload")]
+mod watcher;
+
+#[cfg(feature = "serde")]
+pub use palette::Palette;
+#[cfg(feature = "hot_reload")]
+pub use watcher::{ReloadError, ThemeWatcher};
+
+/// A collection of named [`Garnishes`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Debug, Default, Clone)]
+pub struct Theme<'a> {
+    entries: BTreeMap<String, ThemeEntry<'a>>,
+}
+
+/// An entry of a [`Theme`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Debug, Default, Clone)]
+pub struct ThemeEntry<'a> {
+    /// The name of the entry whose garnishes come before these garnishes.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub extends: Option<String>,
+    /// The garnishes of the entry.
+    pub garnishes: Garnishes<'a>,
+}
+
+/// Errors resolving the garnishes of a [`Theme`] entry.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ThemeError {
+    /// There is no entry with the given name.
+    Missing(String),
+    /// The entry with the given name extends itself, directly or indirectly.
+    Cycle(String),
+}
+
+impl<'a> Theme<'a> {
+    /// Creates an empty theme.
+    #[must_use = "constructor returns a new instance"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts an entry, returning the entry previously stored under `name`.
+    pub fn insert<N, E>(&mut self, name: N, entry: E) -> Option<ThemeEntry<'a>>
+    where
+        N: Into<String>,
+        E: Into<ThemeEntry<'a>>,
+    {
+        self.entries.insert(name.into(), entry.into())
+    }
+
+    /// Removes the entry with `name`.
+    pub fn remove(&mut self, name: &str) -> Option<ThemeEntry<'a>> {
+        self.entries.remove(name)
+    }
+
+    /// Returns the entry with `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&ThemeEntry<'a>> {
+        self.entries.get(name)
+    }
+
+    /// Returns `true` if the theme has an entry with `name`.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.contains_key(name)
+    }
+
+    /// Returns an iterator over the names of the entries, in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Returns the garnishes of the entry with `name`, including the
+    /// garnishes of the entries it extends.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeError::Missing`] if `name`, or an entry it extends,
+    /// doesn't exist and [`ThemeError::Cycle`] if an entry extends itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_garnish::{
+    ///     Padding, garnishes,
+    ///     theme::{Theme, ThemeEntry, ThemeError},
+    /// };
+    ///
+    /// let mut theme = Theme::new();
+    /// theme.insert("base", garnishes![Padding::uniform(1)]);
+    /// theme.insert("popup", ThemeEntry::default().extends("base"));
+    ///
+    /// assert_eq!(theme.garnishes("popup").unwrap().len(), 1);
+    /// assert_eq!(theme.garnishes("menu").unwrap_err(), ThemeError::Missing("menu".into()));
+    /// ```
+    pub fn garnishes(&self, name: &str) -> Result<Garnishes<'a>, ThemeError> {
+        let mut chain: Vec<&str> = Vec::new();
+        let mut current = name;
+        loop {
+            if chain.contains(&current) {
+                return Err(ThemeError::Cycle(current.to_string()));
+            }
+            let entry = self
+                .get(current)
+                .ok_or_else(|| ThemeError::Missing(current.to_string()))?;
+            chain.push(current);
+            match &entry.extends {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+
+        Ok(chain
+            .iter()
+            .rev()
+            .flat_map(|name| self.entries[*name].garnishes.iter().cloned())
+            .collect())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Theme<'_> {
+    /// Deserializes a theme, resolving color references with the palette
+    /// of the theme file overridden by `palette`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deserializing fails or a color reference is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use ratatui_garnish::theme::{Palette, Theme};
+    ///
+    /// let json = r#"{
+    ///     "$palette": { "accent": "Yellow" },
+    ///     "panel": { "garnishes": [
+    ///         { "Style": { "fg": "$accent", "add_modifier": "", "sub_modifier": "" } }
+    ///     ] }
+    /// }"#;
+    ///
+    /// let light: Palette = [("accent", Color::Blue)].into_iter().collect();
+    /// let mut deserializer = serde_json::Deserializer::from_str(json);
+    /// let theme = Theme::deserialize_with_palette(&mut deserializer, &light).unwrap();
+    ///
+    /// let garnishes = theme.garnishes("panel").unwrap();
+    /// assert_eq!(garnishes[0].try_as_style_ref().unwrap().fg, Some(Color::Blue));
+    /// ```
+    pub fn deserialize_with_palette<'de, D>(
+        deserializer: D,
+        palette: &Palette,
+    ) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ThemeVisitor {
+            palette,
+            _theme: std::marker::PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Theme<'_> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Self::deserialize_with_palette(deserializer, &Palette::default())
+    }
+}
+
+/// The key of the palette in a theme file.
+#[cfg(feature = "serde")]
+const PALETTE_KEY: &str = "$palette";
+
+/// Deserializes a theme; the `$palette` key holds the palette, all other keys are entries.
+#[cfg(feature = "serde")]
+struct ThemeVisitor<'p, 'a> {
+    palette: &'p Palette,
+    _theme: std::marker::PhantomData<Theme<'a>>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'a> serde::de::Visitor<'de> for ThemeVisitor<'_, 'a> {
+    type Value = Theme<'a>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map of theme entries")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Theme<'a>, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let mut active = self.palette.activate();
+        let mut theme = Theme::new();
+
+        while let Some(name) = map.next_key::<String>()? {
+            if name == PALETTE_KEY {
+                // entries are resolved as they are read, so they can't
+                // reference a palette that comes after them
+                if !theme.entries.is_empty() {
+                    return Err(A::Error::custom(
+                        "the `$palette` table must come before the theme entries",
+                    ));
+                }
+                let mut palette: Palette = map.next_value()?;
+                palette.extend(self.palette.iter());
+                drop(active);
+                active = palette.activate();
+            } else if name.starts_with('$') {
+                return Err(A::Error::custom(format_args!(
+                    "theme entry names starting with `$` are reserved: `{name}`"
+                )));
+            } else {
+                let entry: ThemeEntry<'a> = map.next_value()?;
+                theme.entries.insert(name, entry);
+            }
+        }
+        drop(active);
+
+        Ok(theme)
+    }
+}
+
+impl<'a> ThemeEntry<'a> {
+    /// Creates an entry with `garnishes`.
+    #[must_use = "constructor returns a new instance"]
+    pub fn new<G: Into<Garnishes<'a>>>(garnishes: G) -> Self {
+        Self {
+            extends: None,
+            garnishes: garnishes.into(),
+        }
+    }
+
+    /// Sets the name of the entry this entry extends.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub fn extends<N: Into<String>>(mut self, name: N) -> Self {
+        self.extends = Some(name.into());
+        self
+    }
+}
+
+impl<'a> From<Garnishes<'a>> for ThemeEntry<'a> {
+    fn from(garnishes: Garnishes<'a>) -> Self {
+        Self::new(garnishes)
+    }
+}
+
+impl<'a> From<Vec<Garnish<'a>>> for ThemeEntry<'a> {
+    fn from(garnishes: Vec<Garnish<'a>>) -> Self {
+        Self::new(garnishes)
+    }
+}
+
+impl<'a, N: Into<String>, E: Into<ThemeEntry<'a>>> FromIterator<(N, E)> for Theme<'a> {
+    fn from_iter<I: IntoIterator<Item = (N, E)>>(iter: I) -> Self {
+        Self {
+            entries: iter
+                .into_iter()
+                .map(|(name, entry)| (name.into(), entry.into()))
+                .collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing(name) => write!(f, "theme has no entry `{name}`"),
+            Self::Cycle(name) => write!(f, "theme entry `{name}` extends itself"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Padding, border::PlainBorder};
+    use ratatui::style::{Color, Style};
+
+    #[test]
+    fn garnishes_include_extended_entries() {
+        let theme: Theme = [
+            (
+                "base",
+                ThemeEntry::new(vec![Garnish::from(Padding::uniform(1))]),
+            ),
+            (
+                "panel",
+                ThemeEntry::new(vec![Garnish::from(PlainBorder::default())]).extends("base"),
+            ),
+            (
+                "panel.focused",
+                ThemeEntry::new(vec![Garnish::from(Style::default().fg(Color::Yellow))])
+                    .extends("panel"),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let garnishes = theme.garnishes("panel.focused").unwrap();
+        assert_eq!(garnishes.len(), 3);
+        assert!(garnishes[0].is_padding());
+        assert!(garnishes[1].is_plain_border());
+        assert!(garnishes[2].is_style());
+    }
+
+    #[test]
+    fn garnishes_report_errors() {
+        let mut theme = Theme::new();
+        theme.insert("a", ThemeEntry::default().extends("b"));
+        theme.insert("b", ThemeEntry::default().extends("a"));
+        theme.insert("c", ThemeEntry::default().extends("d"));
+
+        assert_eq!(
+            theme.garnishes("a").unwrap_err(),
+            ThemeError::Cycle("a".into())
+        );
+        assert_eq!(
+            theme.garnishes("c").unwrap_err(),
+            ThemeError::Missing("d".into())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn theme_deserialization() {
+        use ratatui::style::Stylize;
+
+        let json = r#"{
+            "panel": {
+                "garnishes": [
+                    { "PlainBorder": "ALL" },
+                    { "Padding": { "left": 1, "right": 1 } }
+                ]
+            },
+            "panel.focused": {
+                "extends": "panel",
+                "garnishes": [
+                    { "Style": { "fg": "Yellow", "add_modifier": "BOLD", "sub_modifier": "" } }
+                ]
+            }
+        }"#;
+
+        let theme: Theme = serde_json::from_str(json).unwrap();
+        let garnishes = theme.garnishes("panel.focused").unwrap();
+        assert_eq!(garnishes.len(), 3);
+        assert_eq!(
+            garnishes[0].try_as_plain_border_ref(),
+            Some(&PlainBorder::default())
+        );
+        assert_eq!(
+            garnishes[1].try_as_padding_ref(),
+            Some(&Padding::horizontal(1))
+        );
+        assert_eq!(
+            garnishes[2].try_as_style_ref(),
+            Some(&Style::default().fg(Color::Yellow).bold())
+        );
+
+        let json = serde_json::to_string(&theme).unwrap();
+        let restored: Theme = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            restored.names().collect::<Vec<_>>(),
+            ["panel", "panel.focused"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn theme_palette_deserialization() {
+        let json = r#"{
+            "$palette": { "accent": "Yellow", "border": "Blue" },
+            "panel": {
+                "garnishes": [
+                    { "Style": { "fg": "$border", "bg": "$accent", "add_modifier": "", "sub_modifier": "" } },
+                    { "TitleTop": {
+                        "spans": [{ "content": "$USER $accent", "style": { "add_modifier": "", "sub_modifier": "" } }],
+                        "style": { "add_modifier": "", "sub_modifier": "" },
+                        "margin": 0
+                    } }
+                ]
+            },
+            "palette": { "garnishes": [] }
+        }"#;
+        let dark: Palette = [("border", Color::DarkGray)].into_iter().collect();
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        let theme = Theme::deserialize_with_palette(&mut deserializer, &dark).unwrap();
+
+        let garnishes = theme.garnishes("panel").unwrap();
+        assert_eq!(
+            garnishes[0].try_as_style_ref(),
+            Some(&Style::default().fg(Color::DarkGray).bg(Color::Yellow))
+        );
+        let title = garnishes[1].try_as_title_top_ref().unwrap();
+        assert_eq!(title.spans[0].content, "$USER $accent");
+        // an entry named `palette` is an ordinary entry
+        assert_eq!(theme.garnishes("palette").unwrap().len(), 0);
+
+        let error =
+            serde_json::from_str::<Theme>(r#"{ "$panel": { "garnishes": [] } }"#).unwrap_err();
+        assert!(error.to_string().contains("reserved: `$panel`"));
+
+        let error = serde_json::from_str::<Theme>(
+            r#"{ "panel": { "garnishes": [] }, "$palette": { "accent": "Yellow" } }"#,
+        )
+        .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("must come before the theme entries")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn theme_cbor_round_trip() {
+        // CBOR isn't human readable, flags are serialized as their bits
+        let json: serde_json::Value = serde_json::from_str(
+            r#"{
+                "$palette": { "accent": "Yellow" },
+                "panel": { "garnishes": [
+                    { "PlainBorder": 15 },
+                    { "Style": { "fg": "$accent", "add_modifier": 0, "sub_modifier": 0 } }
+                ] }
+            }"#,
+        )
+        .unwrap();
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&json, &mut cbor).unwrap();
+
+        let theme: Theme = ciborium::from_reader(cbor.as_slice()).unwrap();
+        let garnishes = theme.garnishes("panel").unwrap();
+        assert!(garnishes[0].is_plain_border());
+        assert_eq!(
+            garnishes[1].try_as_style_ref(),
+            Some(&Style::default().fg(Color::Yellow))
+        );
+
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&theme, &mut cbor).unwrap();
+        let restored: Theme = ciborium::from_reader(cbor.as_slice()).unwrap();
+        assert_eq!(restored.names().collect::<Vec<_>>(), ["panel"]);
+        assert_eq!(
+            restored.garnishes("panel").unwrap()[1].try_as_style_ref(),
+            Some(&Style::default().fg(Color::Yellow))
+        );
+    }
+}
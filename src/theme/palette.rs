@@ -0,0 +1,325 @@
+use std::{cell::RefCell, collections::BTreeMap, fmt, str::FromStr};
+
+use ratatui::style::{Color, Modifier, Style};
+use serde::de::{
+    self, Deserialize, Deserializer, MapAccess, Visitor, value::MapAccessDeserializer,
+};
+
+/// Named colors that serialized garnishes reference as `"$name"`.
+///
+/// Deserializing with [`Palette::resolve`] replaces a color of the form
+/// `$name` with the color `name` from the palette. An unknown name is an
+/// error. References are resolved where a garnish deserializes a color, so
+/// only colors are replaced: other strings, like the text of a title, are
+/// left untouched.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Color;
+/// use ratatui_garnish::{Garnishes, theme::Palette};
+///
+/// let palette: Palette = [("accent", Color::Yellow)].into_iter().collect();
+///
+/// let json = r#"[{ "Style": { "fg": "$accent", "add_modifier": "", "sub_modifier": "" } }]"#;
+/// let mut deserializer = serde_json::Deserializer::from_str(json);
+/// let garnishes: Garnishes = palette.resolve(&mut deserializer).unwrap();
+///
+/// assert_eq!(garnishes[0].try_as_style_ref().unwrap().fg, Some(Color::Yellow));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Palette {
+    colors: BTreeMap<String, Color>,
+}
+
+impl Palette {
+    /// Creates an empty palette.
+    #[must_use = "constructor returns a new instance"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the color `name`, returning the previous color.
+    pub fn insert<N: Into<String>>(&mut self, name: N, color: Color) -> Option<Color> {
+        self.colors.insert(name.into(), color)
+    }
+
+    /// Returns the color `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.colors.get(name).copied()
+    }
+
+    /// Returns an iterator over the names and colors, in sorted order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Color)> {
+        self.colors
+            .iter()
+            .map(|(name, color)| (name.as_str(), *color))
+    }
+
+    /// Deserializes a `T`, replacing references to colors of this palette.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deserializing fails or a reference is unknown.
+    pub fn resolve<'de, T, D>(&self, deserializer: D) -> Result<T, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let _active = self.activate();
+        T::deserialize(deserializer)
+    }
+
+    /// Makes this palette the one colors reference, until the returned
+    /// guard is dropped.
+    pub(super) fn activate(&self) -> Active {
+        Active(ACTIVE.replace(Some(self.clone())))
+    }
+}
+
+impl<N: Into<String>> FromIterator<(N, Color)> for Palette {
+    fn from_iter<I: IntoIterator<Item = (N, Color)>>(iter: I) -> Self {
+        Self {
+            colors: iter
+                .into_iter()
+                .map(|(name, color)| (name.into(), color))
+                .collect(),
+        }
+    }
+}
+
+impl<N: Into<String>> Extend<(N, Color)> for Palette {
+    fn extend<I: IntoIterator<Item = (N, Color)>>(&mut self, iter: I) {
+        self.colors
+            .extend(iter.into_iter().map(|(name, color)| (name.into(), color)));
+    }
+}
+
+// ===== Resolving Colors =====
+//
+// Serde has no way to pass state to a `Deserialize` impl, so the palette of
+// the running `Palette::resolve` is kept in a thread local. The color and
+// style fields of the garnishes deserialize with the functions below, which
+// look references up in it.
+
+thread_local! {
+    /// The palette colors reference, set while a palette resolves.
+    static ACTIVE: RefCell<Option<Palette>> = const { RefCell::new(None) };
+}
+
+/// Restores the previously active palette when dropped.
+pub(super) struct Active(Option<Palette>);
+
+impl Drop for Active {
+    fn drop(&mut self) {
+        ACTIVE.set(self.0.take());
+    }
+}
+
+/// Deserializes a color, which may reference the active palette.
+pub(crate) fn deserialize_color<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Color, D::Error> {
+    // like `Color` itself, needs a self-describing format to accept both
+    // strings and the maps of the old color format
+    deserializer.deserialize_any(ColorVisitor)
+}
+
+/// Deserializes a style, whose colors may reference the active palette.
+pub(crate) fn deserialize_style<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Style, D::Error> {
+    StyleDef::deserialize(deserializer)
+}
+
+/// Deserializes an optional style, whose colors may reference the active
+/// palette.
+pub(crate) fn deserialize_option_style<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Style>, D::Error> {
+    #[derive(serde::Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "deserialize_style")] Style);
+
+    Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(style)| style))
+}
+
+fn deserialize_option_color<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Color>, D::Error> {
+    #[derive(serde::Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "deserialize_color")] Color);
+
+    Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(color)| color))
+}
+
+/// Mirrors `Style`, resolving its colors.
+#[derive(serde::Deserialize)]
+#[serde(remote = "Style", rename = "Style")]
+struct StyleDef {
+    #[serde(default, deserialize_with = "deserialize_option_color")]
+    fg: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_option_color")]
+    bg: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_option_color")]
+    underline_color: Option<Color>,
+    add_modifier: Modifier,
+    sub_modifier: Modifier,
+}
+
+struct ColorVisitor;
+
+impl<'de> Visitor<'de> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a color or a `$name` palette reference")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Color, E> {
+        let Some(name) = value.strip_prefix('$') else {
+            return Color::from_str(value).map_err(E::custom);
+        };
+        ACTIVE
+            .with_borrow(|palette| palette.as_ref().and_then(|palette| palette.get(name)))
+            .ok_or_else(|| E::custom(format_args!("unknown palette color `${name}`")))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Color, A::Error> {
+        Color::deserialize(MapAccessDeserializer::new(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Garnishes,
+        shadow::{Bevel, Shadow, SoftShadow, Tint},
+        title::Top,
+    };
+    use ratatui::style::Style;
+
+    fn palette() -> Palette {
+        [
+            ("accent", Color::Yellow),
+            ("bg.dark", Color::Rgb(16, 16, 16)),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn resolve(json: &str) -> Result<Garnishes<'static>, serde_json::Error> {
+        palette().resolve(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[test]
+    fn references_resolve_to_colors() {
+        let garnishes = resolve(
+            r#"[
+                { "Style": { "fg": "$accent", "bg": "$bg.dark", "add_modifier": "", "sub_modifier": "" } },
+                { "TitleTop": {
+                    "spans": [{ "content": "$accent", "style": { "fg": "$accent", "add_modifier": "", "sub_modifier": "" } }],
+                    "style": { "add_modifier": "", "sub_modifier": "" },
+                    "margin": 0
+                } }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            garnishes[0].try_as_style_ref(),
+            Some(
+                &Style::default()
+                    .fg(Color::Yellow)
+                    .bg(Color::Rgb(16, 16, 16))
+            )
+        );
+        let title = garnishes[1].try_as_title_top_ref().unwrap();
+        assert_eq!(title.spans[0].content, "$accent");
+        assert_eq!(title.spans[0].style.fg, Some(Color::Yellow));
+    }
+
+    #[test]
+    fn unknown_references_are_errors() {
+        let error = resolve(
+            r#"[{ "Style": { "fg": "$missing", "add_modifier": "", "sub_modifier": "" } }]"#,
+        )
+        .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("unknown palette color `$missing`")
+        );
+    }
+
+    #[test]
+    fn other_strings_are_untouched() {
+        let title: crate::title::Title<Top> = palette()
+            .resolve(&mut serde_json::Deserializer::from_str(
+                r#"{ "spans": [{ "content": "$5 off", "style": { "add_modifier": "", "sub_modifier": "" } }],
+                     "style": { "add_modifier": "", "sub_modifier": "" }, "margin": 0 }"#,
+            ))
+            .unwrap();
+        assert_eq!(title.spans[0].content, "$5 off");
+
+        // references of unknown colors in text aren't errors either
+        let garnishes = resolve(
+            r#"[{ "TitleTop": {
+                "spans": [{ "content": "$USER", "style": { "add_modifier": "", "sub_modifier": "" } }],
+                "style": { "add_modifier": "", "sub_modifier": "" },
+                "margin": 0
+            } }]"#,
+        )
+        .unwrap();
+        let title = garnishes[0].try_as_title_top_ref().unwrap();
+        assert_eq!(title.spans[0].content, "$USER");
+    }
+
+    #[test]
+    fn colors_of_garnishes_resolve() {
+        let garnishes = resolve(
+            r#"[
+                { "Shadow": { "x_offset": 1, "y_offset": 1, "symbol": "▒",
+                              "tint": { "color": "$accent", "amount": 40 } } },
+                { "Gradient": { "start": "$accent", "end": "$bg.dark" } }
+            ]"#,
+        )
+        .unwrap();
+        let gradient = garnishes[1].try_as_gradient_ref().unwrap();
+        assert_eq!(
+            (gradient.start, gradient.end),
+            (Color::Yellow, Color::Rgb(16, 16, 16))
+        );
+        let shadow = Shadow::new(1, 1).tint(Tint::new(Color::Yellow, 40));
+        assert_eq!(garnishes[0].try_as_shadow_ref(), Some(&shadow));
+    }
+
+    #[test]
+    fn colors_of_nested_fields_resolve() {
+        let garnishes = resolve(
+            r#"[
+                { "CustomBorder": { "styles": { "top": { "fg": "$accent", "add_modifier": "", "sub_modifier": "" } } } },
+                { "SoftShadow": { "tint": { "color": "$bg.dark" } } },
+                { "Bevel": { "shade": { "color": "$bg.dark" }, "highlight": { "color": "$accent" } } },
+                { "Pulse": { "start": "$accent", "end": { "Rgb": [1, 2, 3] } } }
+            ]"#,
+        )
+        .unwrap();
+
+        let border = garnishes[0].try_as_custom_border_ref().unwrap();
+        assert_eq!(border.styles.top, Some(Style::new().fg(Color::Yellow)));
+        let soft_shadow = SoftShadow::default().tint(Tint::new(Color::Rgb(16, 16, 16), 50));
+        assert_eq!(garnishes[1].try_as_soft_shadow_ref(), Some(&soft_shadow));
+        let bevel = Bevel::default()
+            .shade(Tint::new(Color::Rgb(16, 16, 16), 50))
+            .highlight(Tint::new(Color::Yellow, 50));
+        assert_eq!(garnishes[2].try_as_bevel_ref(), Some(&bevel));
+        let pulse = garnishes[3].try_as_pulse_ref().unwrap();
+        assert_eq!(
+            (pulse.start, pulse.end),
+            (Color::Yellow, Color::Rgb(1, 2, 3))
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+use super::Theme;
+
+/// A parser turning the contents of a theme file into a [`Theme`].
+type Parser<E> = Box<dyn Fn(&str) -> Result<Theme<'static>, E> + Send>;
+
+/// Reloads a [`Theme`] when its file changes.
+///
+/// `ThemeWatcher` polls the modification time and size of the file, it
+/// doesn't use platform specific APIs. Call [`ThemeWatcher::poll`] between
+/// frames: when the file changed it is parsed and the theme is replaced.
+/// If reading or parsing fails the error is returned and the last good
+/// theme stays active.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ratatui::text::Line;
+/// use ratatui_garnish::{GarnishableWidget, theme::ThemeWatcher};
+///
+/// let mut watcher = ThemeWatcher::new("theme.json", |source| serde_json::from_str(source))?;
+///
+/// loop {
+///     if let Err(error) = watcher.poll() {
+///         eprintln!("keeping previous theme: {error}");
+///     }
+///     let widget = Line::raw("Hello").garnish_from_theme(watcher.theme(), "panel");
+///     // render the widget...
+/// #   break;
+/// }
+/// # Ok::<(), ratatui_garnish::theme::ReloadError<serde_json::Error>>(())
+/// ```
+pub struct ThemeWatcher<E> {
+    path: PathBuf,
+    parse: Parser<E>,
+    interval: Duration,
+    last_poll: Option<Instant>,
+    /// The file at the last check, `None` if it couldn't be read.
+    stamp: Option<FileStamp>,
+    theme: Theme<'static>,
+}
+
+/// Errors reloading a theme file.
+#[derive(Debug)]
+pub enum ReloadError<E> {
+    /// Reading the file failed.
+    Io(std::io::Error),
+    /// Parsing the file failed.
+    Parse(E),
+}
+
+/// The modification time and length of a file, used to detect changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+impl<E> ThemeWatcher<E> {
+    /// Loads the theme at `path` with `parse` and watches it for changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or parsed.
+    pub fn new<P, F>(path: P, parse: F) -> Result<Self, ReloadError<E>>
+    where
+        P: Into<PathBuf>,
+        F: Fn(&str) -> Result<Theme<'static>, E> + Send + 'static,
+    {
+        let mut watcher = Self {
+            path: path.into(),
+            parse: Box::new(parse),
+            interval: Duration::from_millis(250),
+            last_poll: None,
+            stamp: None,
+            theme: Theme::default(),
+        };
+        watcher.reload()?;
+        Ok(watcher)
+    }
+
+    /// Sets the minimum time between checks of the file, 250ms by default.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Returns the path of the theme file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the last successfully loaded theme.
+    #[must_use]
+    pub const fn theme(&self) -> &Theme<'static> {
+        &self.theme
+    }
+
+    /// Reloads the theme if the file changed since the last check.
+    ///
+    /// Returns `true` if the theme was replaced. The file is checked at
+    /// most once per interval; a file that fails to load is reported once
+    /// and retried when it changes again, a missing file when it reappears.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the changed file can't be read or parsed, the
+    /// previous theme is kept.
+    pub fn poll(&mut self) -> Result<bool, ReloadError<E>> {
+        let now = Instant::now();
+        if self
+            .last_poll
+            .is_some_and(|last_poll| now.duration_since(last_poll) < self.interval)
+        {
+            return Ok(false);
+        }
+        self.last_poll = Some(now);
+
+        let stamp = match self.file_stamp() {
+            Ok(stamp) => Some(stamp),
+            // already reported, retried when the file can be read again
+            Err(_) if self.stamp.is_none() => return Ok(false),
+            Err(error) => {
+                self.stamp = None;
+                return Err(error);
+            }
+        };
+        if stamp == self.stamp {
+            return Ok(false);
+        }
+        self.reload().map(|()| true)
+    }
+
+    /// Reads and parses the theme file, replacing the theme on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or parsed, the previous
+    /// theme is kept.
+    pub fn reload(&mut self) -> Result<(), ReloadError<E>> {
+        match self.file_stamp() {
+            Ok(stamp) => self.stamp = Some(stamp),
+            Err(error) => {
+                self.stamp = None;
+                return Err(error);
+            }
+        }
+        let source = std::fs::read_to_string(&self.path).map_err(ReloadError::Io)?;
+        self.theme = (self.parse)(&source).map_err(ReloadError::Parse)?;
+        Ok(())
+    }
+
+    fn file_stamp(&self) -> Result<FileStamp, ReloadError<E>> {
+        let metadata = std::fs::metadata(&self.path).map_err(ReloadError::Io)?;
+        Ok(FileStamp {
+            modified: metadata.modified().ok(),
+            len: metadata.len(),
+        })
+    }
+}
+
+impl<E> fmt::Debug for ThemeWatcher<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThemeWatcher")
+            .field("path", &self.path)
+            .field("interval", &self.interval)
+            .field("theme", &self.theme)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ReloadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read theme: {error}"),
+            Self::Parse(error) => write!(f, "failed to parse theme: {error}"),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for ReloadError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Parse(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    const PANEL: &str = r#"{ "panel": { "garnishes": [{ "Padding": { "left": 1 } }] } }"#;
+    const POPUP: &str = r#"{ "popup": { "garnishes": [{ "Padding": { "top": 1 } }] } }"#;
+
+    fn write(path: &Path, contents: &str, modified: SystemTime) {
+        std::fs::write(path, contents).unwrap();
+        File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+    }
+
+    #[test]
+    fn poll_reloads_changed_files() {
+        let path =
+            std::env::temp_dir().join(format!("ratatui-garnish-theme-{}.json", std::process::id()));
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        write(&path, PANEL, start);
+
+        let mut watcher = ThemeWatcher::new(&path, |source| serde_json::from_str(source))
+            .unwrap()
+            .interval(Duration::ZERO);
+        assert!(watcher.theme().contains("panel"));
+        assert!(!watcher.poll().unwrap());
+
+        // same length, only the modification time changed
+        write(&path, POPUP, start + Duration::from_secs(1));
+        assert!(watcher.poll().unwrap());
+        assert!(watcher.theme().contains("popup"));
+
+        write(&path, "{ invalid", start + Duration::from_secs(2));
+        assert!(matches!(watcher.poll(), Err(ReloadError::Parse(_))));
+        assert!(watcher.theme().contains("popup"));
+        assert!(!watcher.poll().unwrap());
+
+        // a missing file is reported once, until it is back
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(watcher.poll(), Err(ReloadError::Io(_))));
+        assert!(!watcher.poll().unwrap());
+        assert!(!watcher.poll().unwrap());
+        assert!(watcher.theme().contains("popup"));
+
+        write(&path, PANEL, start + Duration::from_secs(3));
+        assert!(watcher.poll().unwrap());
+        assert!(watcher.theme().contains("panel"));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
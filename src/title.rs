@@ -24,7 +24,41 @@
 //!
 //! The `margin` field controls left/right margins for horizontal titles, and top/bottom margins
 //! for vertical titles. Use margins to prevent titles from overlapping with border corners or
-//! to create visual spacing.
+//! to create visual spacing. Titles are aligned within the space between the margins.
+//!
+//! # Offsets and Caps
+//!
+//! Instead of aligning a title, [`Title::offset`] places it at a number of
+//! cells or a percentage along its side, so several titles can label
+//! sections of a long border. Caps drawn around the title, e.g. `┤ Name ├`,
+//! cut it into the border line like the legend of a fieldset;
+//! [`Title::border_caps`] picks them from the [`BorderSet`] of the border.
+//!
+//! # Truncation and Groups
+//!
+//! Titles longer than their side are clipped by default. A [`Truncation`]
+//! strategy replaces the end, middle or start with an ellipsis instead.
+//! Separate titles on the same side overdraw each other; put them in a
+//! [`TitleGroup`] to lay them out side by side, shortening the titles with
+//! the lowest priority first.
+//!
+//! # Wide Characters
+//!
+//! Vertical titles put every grapheme on its own row. Titles with wide
+//! graphemes, e.g. CJK characters, get a column wide enough for them; see
+//! [`WideGlyphs`] to replace them instead.
+//!
+//! # Orientation and Columns
+//!
+//! Vertical titles read from top to bottom. An [`Orientation`] turns them
+//! to read from bottom to top instead, and [`Title::columns`] wraps long
+//! labels into several columns.
+//!
+//! # Multi-line Titles
+//!
+//! A [`TextTitle`] turns a `Text` into a title reserving a row for every
+//! line above or below the widget, or a column before or after it, e.g. a
+//! header with a subtitle.
 //!
 //! # Style Inheritance
 //!
@@ -72,17 +106,25 @@
 //! ```
 use std::{borrow::Cow, marker::PhantomData};
 
-use crate::RenderModifier;
+use crate::{
+    HitPart, RenderModifier,
+    border::{BorderSet, Borders},
+};
 use derive_more::{Deref, DerefMut};
 use ratatui::{
     buffer::Buffer,
-    layout::{Alignment, Rect},
+    layout::{Alignment, Position, Rect},
     style::{Style, Styled},
     text::{Line, Span},
-    widgets::WidgetRef,
 };
 use unicode_width::UnicodeWidthStr;
 
+mod group;
+mod text;
+
+pub use group::TitleGroup;
+pub use text::TextTitle;
+
 /// A wrapper around [`Line`] with additional positioning and margin control.
 ///
 /// The generic parameter `Position` determines where the title is rendered relative to the widget.
@@ -95,9 +137,96 @@ pub struct Title<'a, Position: TitlePosition> {
     #[deref_mut]
     line: Line<'a>,
     margin: u8,
+    offset: Option<TitleOffset>,
+    caps: Option<[char; 2]>,
+    truncation: Truncation,
+    wide_glyphs: WideGlyphs,
+    orientation: Orientation,
+    columns: u16,
     _position: PhantomData<Position>,
 }
 
+/// The place of a title along its side, measured from the start of the
+/// side after the margin.
+///
+/// For horizontal titles the start is the left end of the side, for
+/// vertical titles the top. Titles are moved back when they would run past
+/// the end of the side.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TitleOffset {
+    /// A number of cells from the start.
+    Absolute(u16),
+    /// A percentage of the length of the side, from 0 to 100.
+    Percent(u8),
+}
+
+impl TitleOffset {
+    /// Returns the offset in cells on a side of `length` cells.
+    fn resolve(self, length: u16) -> u16 {
+        match self {
+            Self::Absolute(cells) => cells,
+            Self::Percent(percent) => {
+                let cells = u32::from(length) * u32::from(percent.min(100)) / 100;
+                u16::try_from(cells).unwrap_or(length)
+            }
+        }
+    }
+}
+
+/// How a title too long for its side is shortened.
+///
+/// The ellipsis takes the style of the text it replaces.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Truncation {
+    /// Cuts the text off following the alignment, like a `Line`: left
+    /// aligned titles lose their end, right aligned titles their start and
+    /// centered titles both. The default.
+    #[default]
+    Clip,
+    /// Replaces the end with an ellipsis, `Quarterly re…`.
+    End,
+    /// Replaces the middle with an ellipsis, `Quarte…report`.
+    Middle,
+    /// Replaces the start with an ellipsis, `…terly report`.
+    Start,
+}
+
+/// How vertical titles render graphemes wider than one cell, e.g. CJK
+/// characters or emoji.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum WideGlyphs {
+    /// Widens the column of the title to the widest grapheme, the default.
+    ///
+    /// Reserved positions reserve the wider column. Overlay positions
+    /// draw over the cells next to the edge; narrow graphemes stay on the
+    /// edge.
+    #[default]
+    Widen,
+    /// Replaces wide graphemes with a character, keeping the column one
+    /// cell wide.
+    Replace(char),
+}
+
+/// The direction vertical titles are read in.
+///
+/// Terminals can't rotate characters, so the graphemes stay upright and
+/// are stacked one per row in reading order. Alignment, offset and
+/// truncation follow the reading direction: a left aligned title reading
+/// from bottom to top starts at the bottom of its side.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Orientation {
+    /// Reads from top to bottom, like text turned clockwise, the default.
+    #[default]
+    TopToBottom,
+    /// Reads from bottom to top, like text turned counterclockwise, e.g.
+    /// the label of the y axis of a chart.
+    BottomToTop,
+}
+
 // ===== Position Marker Types =====
 
 /// Marker trait for title positioning.
@@ -175,6 +304,12 @@ impl<'a, Position: TitlePosition> Title<'a, Position> {
         Self {
             line: Line::raw(content),
             margin: 0,
+            offset: None,
+            caps: None,
+            truncation: Truncation::Clip,
+            wide_glyphs: WideGlyphs::Widen,
+            orientation: Orientation::TopToBottom,
+            columns: 1,
             _position: PhantomData,
         }
     }
@@ -199,6 +334,12 @@ impl<'a, Position: TitlePosition> Title<'a, Position> {
         Self {
             line: Line::styled(content, style),
             margin: 0,
+            offset: None,
+            caps: None,
+            truncation: Truncation::Clip,
+            wide_glyphs: WideGlyphs::Widen,
+            orientation: Orientation::TopToBottom,
+            columns: 1,
             _position: PhantomData,
         }
     }
@@ -343,11 +484,422 @@ impl<'a, Position: TitlePosition> Title<'a, Position> {
         self.alignment(Alignment::Right)
     }
 
-    /// Calculates the render area for a title positioned at the top.
+    /// Places the title at an offset along its side, overriding the alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_garnish::title::{Title, TitleOffset, Top};
+    ///
+    /// let title = Title::<Top>::raw("Address").offset(TitleOffset::Absolute(4));
+    /// let title = Title::<Top>::raw("Payment").offset(TitleOffset::Percent(50));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn offset(mut self, offset: TitleOffset) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Draws `start` and `end` on both sides of the title, above and below
+    /// for vertical titles.
+    ///
+    /// The caps keep the style of the cells they are drawn on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui_garnish::title::{Title, Top};
+    /// let title = Title::<Top>::raw(" Settings ").caps('[', ']');
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn caps(mut self, start: char, end: char) -> Self {
+        self.caps = Some([start, end]);
+        self
+    }
+
+    /// Draws caps cutting the title into the side of a border drawn with `set`.
+    ///
+    /// The caps are taken from the side of the set the title is rendered
+    /// on, see [`BorderSet::caps`]. Together with an offset this labels a
+    /// section of a border like the legend of a fieldset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect, text::Text, widgets::Widget};
+    /// use ratatui_garnish::{
+    ///     GarnishableWidget,
+    ///     border::{BorderSet, PlainBorder},
+    ///     title::{Title, TitleOffset, Top},
+    /// };
+    ///
+    /// let widget = Text::raw("")
+    ///     .garnish(
+    ///         Title::<Top>::raw(" Name ")
+    ///             .margin(1)
+    ///             .offset(TitleOffset::Absolute(1))
+    ///             .border_caps(BorderSet::plain()),
+    ///     )
+    ///     .garnish(PlainBorder::default());
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+    /// widget.render(buffer.area, &mut buffer);
+    /// assert_eq!(buffer, Buffer::with_lines(["┌─┤ Name ├─┐", "│          │", "└──────────┘"]));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub fn border_caps(self, set: BorderSet) -> Self {
+        let [start, end] = set.caps(Position::SIDE);
+        self.caps(start, end)
+    }
+
+    /// Sets how the title is shortened when it doesn't fit its side.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect};
+    /// use ratatui_garnish::{RenderModifier, title::{Title, Top, Truncation}};
+    ///
+    /// let title = Title::<Top>::raw("Quarterly report").truncation(Truncation::Middle);
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 1));
+    /// title.after_render(buffer.area, &mut buffer);
+    /// assert_eq!(buffer, Buffer::with_lines(["Quar…port"]));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn truncation(mut self, truncation: Truncation) -> Self {
+        self.truncation = truncation;
+        self
+    }
+
+    /// Sets how vertical titles render graphemes wider than one cell.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Rect;
+    /// use ratatui_garnish::{RenderModifier, title::{Before, Title, WideGlyphs}};
+    ///
+    /// let area = Rect::new(0, 0, 10, 5);
+    ///
+    /// // the column is widened for the wide characters
+    /// let title = Title::<Before>::raw("設定");
+    /// assert_eq!(title.modify_area(area), Rect::new(2, 0, 8, 5));
+    ///
+    /// let title = Title::<Before>::raw("設定").wide_glyphs(WideGlyphs::Replace('?'));
+    /// assert_eq!(title.modify_area(area), Rect::new(1, 0, 9, 5));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn wide_glyphs(mut self, wide_glyphs: WideGlyphs) -> Self {
+        self.wide_glyphs = wide_glyphs;
+        self
+    }
+
+    /// Sets the direction a vertical title is read in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect};
+    /// use ratatui_garnish::{RenderModifier, title::{Left, Orientation, Title}};
+    ///
+    /// let title = Title::<Left>::raw("abc").orientation(Orientation::BottomToTop);
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 4));
+    /// title.after_render(buffer.area, &mut buffer);
+    /// assert_eq!(buffer, Buffer::with_lines([" ", "c", "b", "a"]));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the number of columns of a vertical title, for labels too long
+    /// for a single column.
+    ///
+    /// Text that doesn't fit a column wraps into the next one, breaking at
+    /// whitespace where possible. The columns follow each other like the
+    /// lines of turned text: right to left when reading from top to bottom,
+    /// left to right when reading from bottom to top. `Before` and `After`
+    /// titles reserve all columns. Titles in a [`TitleGroup`] take a single
+    /// column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect};
+    /// use ratatui_garnish::{RenderModifier, title::{Before, Orientation, Title}};
+    ///
+    /// let title = Title::<Before>::raw("Net income")
+    ///     .orientation(Orientation::BottomToTop)
+    ///     .columns(2);
+    /// assert_eq!(title.modify_area(Rect::new(0, 0, 10, 6)), Rect::new(2, 0, 8, 6));
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 6));
+    /// title.before_render(Rect::new(0, 0, 10, 6), &mut buffer);
+    /// assert_eq!(buffer, Buffer::with_lines([" e", " m", " o", "tc", "en", "Ni"]));
+    /// ```
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub const fn columns(mut self, columns: u16) -> Self {
+        self.columns = if columns == 0 { 1 } else { columns };
+        self
+    }
+
+    /// Whether the title is rendered vertically, on the left or right side.
+    const VERTICAL: bool = Position::SIDE.intersects(Borders::LEFT.union(Borders::RIGHT));
+
+    /// Whether the title is read from bottom to top.
+    const fn reversed(&self) -> bool {
+        Self::VERTICAL && matches!(self.orientation, Orientation::BottomToTop)
+    }
+
+    /// Returns the number of cells the caps take.
+    const fn caps_length(&self) -> u16 {
+        if self.caps.is_some() { 2 } else { 0 }
+    }
+
+    /// Returns the number of cells of `area` along the side of the title.
+    const fn side_length(area: Rect) -> u16 {
+        if Self::VERTICAL {
+            area.height
+        } else {
+            area.width
+        }
+    }
+
+    /// Returns the number of cells `glyph` takes along the side of the title.
+    const fn glyph_length(glyph: &Glyph) -> u16 {
+        if Self::VERTICAL { 1 } else { glyph.width }
+    }
+
+    /// Returns the number of cells the text takes along the side.
+    fn text_length(&self) -> u16 {
+        Self::glyphs_length(&self.glyphs())
+    }
+
+    /// Returns the number of cells `glyphs` take along the side.
+    fn glyphs_length(glyphs: &[Glyph]) -> u16 {
+        glyphs.iter().fold(0, |length, glyph| {
+            length.saturating_add(Self::glyph_length(glyph))
+        })
+    }
+
+    /// Returns the offset and length of the text on a side of `length`
+    /// cells, leaving room for the caps.
+    fn place(&self, length: u16) -> (u16, u16) {
+        self.place_text(self.text_length(), length)
+    }
+
+    /// Returns the offset and length of `text` cells of text on a side of
+    /// `length` cells, measured from the top for reversed titles too.
+    fn place_text(&self, text: u16, length: u16) -> (u16, u16) {
+        let caps = self.caps_length();
+        let space = length.saturating_sub(caps);
+        let (indent, text) = if space < text {
+            (caps / 2, space)
+        } else {
+            let free = space - text;
+            let indent = match (self.offset, self.line.alignment) {
+                (Some(offset), _) => offset.resolve(length).min(free),
+                (None, Some(Alignment::Center)) => free / 2,
+                (None, Some(Alignment::Right)) => free,
+                (None, Some(Alignment::Left) | None) => 0,
+            };
+            (indent + caps / 2, text)
+        };
+        if self.reversed() {
+            let mirrored = length.saturating_sub(indent + text);
+            (mirrored.max(caps / 2), text)
+        } else {
+            (indent, text)
+        }
+    }
+
+    /// Returns the graphemes of the title with their span, zero-width
+    /// graphemes are joined with the grapheme before them.
+    fn glyphs(&self) -> Vec<Glyph<'_>> {
+        let mut glyphs: Vec<Glyph> = Vec::new();
+        for (span, content) in self.line.spans.iter().enumerate() {
+            for grapheme in content.styled_graphemes(self.line.style) {
+                let width = u16::try_from(grapheme.symbol.width()).unwrap_or(u16::MAX);
+                let mut glyph = Glyph {
+                    span,
+                    symbol: Cow::Borrowed(grapheme.symbol),
+                    style: grapheme.style,
+                    width,
+                };
+                match (glyphs.last_mut(), self.wide_glyphs) {
+                    (Some(last), _) if width == 0 => last.symbol.to_mut().push_str(grapheme.symbol),
+                    (_, WideGlyphs::Replace(replacement)) if Self::VERTICAL && width > 1 => {
+                        glyph.symbol = Cow::Owned(replacement.to_string());
+                        glyph.width = 1;
+                        glyphs.push(glyph);
+                    }
+                    _ => glyphs.push(glyph),
+                }
+            }
+        }
+        glyphs
+    }
+
+    /// Returns the part of `glyphs` shown in `length` cells, shortened
+    /// following the truncation strategy when the text doesn't fit.
+    fn fit<'g>(&self, glyphs: Vec<Glyph<'g>>, length: u16) -> Vec<Glyph<'g>> {
+        let total = Self::glyphs_length(&glyphs);
+        if total <= length {
+            return glyphs;
+        }
+        if length == 0 {
+            return Vec::new();
+        }
+
+        let ellipsis = |cut: &Glyph<'g>| Glyph {
+            span: cut.span,
+            symbol: Cow::Borrowed(ELLIPSIS),
+            style: cut.style,
+            width: 1,
+        };
+
+        match self.truncation {
+            Truncation::Clip => {
+                let skip = match (self.offset, self.line.alignment) {
+                    (None, Some(Alignment::Center)) => (total - length) / 2,
+                    (None, Some(Alignment::Right)) => total - length,
+                    _ => 0,
+                };
+                let mut skipped = 0u16;
+                let rest = glyphs.iter().skip_while(|glyph| {
+                    let skip_glyph = skipped < skip;
+                    skipped = skipped.saturating_add(Self::glyph_length(glyph));
+                    skip_glyph
+                });
+                Self::take(rest, length)
+            }
+            Truncation::End => {
+                let mut fitted = Self::take(glyphs.iter(), length - 1);
+                fitted.push(ellipsis(&glyphs[fitted.len()]));
+                fitted
+            }
+            Truncation::Start => {
+                let mut fitted = Self::take(glyphs.iter().rev(), length - 1);
+                fitted.push(ellipsis(&glyphs[glyphs.len() - fitted.len() - 1]));
+                fitted.reverse();
+                fitted
+            }
+            Truncation::Middle => {
+                let budget = length - 1;
+                let mut fitted = Self::take(glyphs.iter(), budget - budget / 2);
+                let mut end = Self::take(glyphs.iter().rev(), budget / 2);
+                fitted.push(ellipsis(&glyphs[fitted.len()]));
+                end.reverse();
+                fitted.append(&mut end);
+                fitted
+            }
+        }
+    }
+
+    /// Takes glyphs while they fit in `budget` cells.
+    fn take<'g: 'b, 'b>(
+        glyphs: impl Iterator<Item = &'b Glyph<'g>>,
+        budget: u16,
+    ) -> Vec<Glyph<'g>> {
+        let mut used = 0u16;
+        glyphs
+            .take_while(|glyph| {
+                used = used.saturating_add(Self::glyph_length(glyph));
+                used <= budget
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Splits the glyphs into at most `limit` lines of `length` cells,
+    /// breaking at whitespace where possible. The last line takes the
+    /// remaining glyphs.
+    fn wrap(&self, length: u16, limit: usize) -> Vec<Vec<Glyph<'_>>> {
+        let is_space = |glyph: &Glyph| glyph.symbol.trim().is_empty();
+        let mut glyphs = self.glyphs();
+        let mut lines = Vec::new();
+        while lines.len() + 1 < limit && glyphs.len() > 1 && Self::glyphs_length(&glyphs) > length {
+            let mut used = 0u16;
+            let fits = glyphs
+                .iter()
+                .take_while(|glyph| {
+                    used = used.saturating_add(Self::glyph_length(glyph));
+                    used <= length
+                })
+                .count()
+                .max(1);
+            let split = glyphs[..=fits]
+                .iter()
+                .rposition(is_space)
+                .filter(|&split| split > 0)
+                .unwrap_or(fits);
+            let rest = glyphs.split_off(split);
+            lines.push(glyphs);
+            glyphs = rest.into_iter().skip_while(is_space).collect();
+        }
+        lines.push(glyphs);
+        lines
+    }
+
+    /// Returns the text areas and glyphs of the lines of the title rendered
+    /// in `area`, the columns of vertical titles.
+    fn lines(&self, area: Rect) -> Vec<(Rect, Vec<Glyph<'_>>)> {
+        if area.is_empty() {
+            return Vec::new();
+        }
+        let lines = if Self::VERTICAL {
+            self.wrap(
+                Self::side_length(area).saturating_sub(self.caps_length()),
+                usize::from(self.columns),
+            )
+        } else {
+            vec![self.glyphs()]
+        };
+        let mut column = 0;
+        lines
+            .into_iter()
+            .filter_map(|glyphs| {
+                let column_area = self.column_area(area, column);
+                column += 1;
+                if column_area.is_empty() {
+                    return None;
+                }
+                Some(self.place_glyphs(column_area, glyphs))
+            })
+            .collect()
+    }
+
+    /// Places `glyphs` on the line `area` following the offset or
+    /// alignment, returning the text area and the glyphs that fit.
+    fn place_glyphs<'g>(&self, area: Rect, glyphs: Vec<Glyph<'g>>) -> (Rect, Vec<Glyph<'g>>) {
+        let (indent, text) = self.place_text(Self::glyphs_length(&glyphs), Self::side_length(area));
+        (Self::sub_area(area, indent, text), self.fit(glyphs, text))
+    }
+
+    /// Returns the part of the render `area` of a vertical title taken by
+    /// column `column`, the whole area for titles with a single column.
+    fn column_area(&self, area: Rect, column: u16) -> Rect {
+        if self.columns <= 1 {
+            return area;
+        }
+        let width = self.column_width();
+        let offset = column.saturating_mul(width);
+        let x = if self.reversed() {
+            area.x.saturating_add(offset)
+        } else {
+            area.right().saturating_sub(offset.saturating_add(width))
+        };
+        Rect { x, width, ..area }.intersection(area)
+    }
+
+    /// Calculates the area of the text of a title positioned at the top.
     ///
     /// Accounts for margins when determining the available width and position.
     fn calculate_top_area(&self, area: Rect) -> Rect {
-        self.calculate_horizontal_area(area, area.y)
+        self.calculate_text_area(self.calculate_above_area(area))
     }
 
     /// Calculates the render area for a title positioned above.
@@ -357,15 +909,14 @@ impl<'a, Position: TitlePosition> Title<'a, Position> {
         self.calculate_horizontal_full_area(area, area.y)
     }
 
-    /// Calculates the render area for a title positioned at the bottom.
+    /// Calculates the area of the text of a title positioned at the bottom.
     ///
     /// Accounts for margins when determining the available width and position.
     fn calculate_bottom_area(&self, area: Rect) -> Rect {
-        let y = area.bottom().saturating_sub(1);
-        self.calculate_horizontal_area(area, y)
+        self.calculate_text_area(self.calculate_below_area(area))
     }
 
-    /// Calculates the render area for a title positioned at the bottom.
+    /// Calculates the render area for a title positioned below.
     ///
     /// Accounts for margins when determining the available width and position.
     fn calculate_below_area(&self, area: Rect) -> Rect {
@@ -393,54 +944,20 @@ impl<'a, Position: TitlePosition> Title<'a, Position> {
         }
     }
 
-    fn calculate_horizontal_area(&self, area: Rect, y: u16) -> Rect {
-        let margin_u16 = u16::from(self.margin);
-        let double_margin = margin_u16.saturating_mul(2);
-
-        if area.width <= double_margin {
-            return Rect::ZERO;
-        }
-
-        let available_width = area.width.saturating_sub(double_margin);
-        let line_width = u16::try_from(self.line.width()).unwrap_or(u16::MAX);
-        let x = area.x.saturating_add(margin_u16);
-
-        if available_width < line_width {
-            return Rect {
-                x,
-                y,
-                width: available_width,
-                height: 1,
-            };
-        }
-
-        let indent_width = match self.line.alignment {
-            Some(Alignment::Center) => area.width.saturating_sub(line_width) / 2,
-            Some(Alignment::Right) => area.width.saturating_sub(line_width),
-            Some(Alignment::Left) | None => 0,
-        };
-
-        Rect {
-            x: x.saturating_add(indent_width),
-            y,
-            width: line_width,
-            height: 1,
-        }
-    }
-
     /// Calculates the render area for a title positioned at the left.
-    const fn calculate_left_area(&self, area: Rect) -> Rect {
-        self.calculate_vertical_area(area, area.x)
+    fn calculate_left_area(&self, area: Rect) -> Rect {
+        let width = self.thickness().min(area.width);
+        self.calculate_vertical_area(area, area.x, width)
     }
 
     /// Calculates the render area for a title positioned at the right.
-    const fn calculate_right_area(&self, area: Rect) -> Rect {
-        let x = area.right().saturating_sub(1);
-        self.calculate_vertical_area(area, x)
+    fn calculate_right_area(&self, area: Rect) -> Rect {
+        let width = self.thickness().min(area.width);
+        self.calculate_vertical_area(area, area.right().saturating_sub(width), width)
     }
 
     /// Calculates the render area for vertical titles with margin consideration.
-    const fn calculate_vertical_area(&self, area: Rect, x: u16) -> Rect {
+    const fn calculate_vertical_area(&self, area: Rect, x: u16, width: u16) -> Rect {
         #[allow(clippy::cast_possible_truncation)]
         let margin_u16 = self.margin as u16;
         let double_margin = margin_u16.saturating_mul(2);
@@ -455,68 +972,259 @@ impl<'a, Position: TitlePosition> Title<'a, Position> {
         Rect {
             x,
             y,
-            width: 1,
+            width,
             height,
         }
     }
 
-    // TODO filter out wide chars?
-    /// Renders text vertically by placing each character on a separate row.
-    fn render_vertical(&self, area: Rect, buffer: &mut Buffer, alignment: Alignment) {
-        if area.height == 0 {
-            return;
+    /// Returns the width of the column of vertical titles, wide enough for
+    /// the widest grapheme. Horizontal titles take a single row.
+    fn column_width(&self) -> u16 {
+        if !Self::VERTICAL {
+            return 1;
         }
+        self.glyphs()
+            .iter()
+            .map(|glyph| glyph.width)
+            .max()
+            .unwrap_or_default()
+            .max(1)
+    }
 
-        let line_width: u16 = self.line.width().try_into().expect("Line too long");
-        let start_y = match alignment {
-            Alignment::Left => area.y, // Top for Left/Before
-            Alignment::Center => area.y + area.height.saturating_sub(line_width) / 2,
-            Alignment::Right => area.y + area.height.saturating_sub(line_width), // Bottom for Right/After
-        };
-        let x = area.x;
-        let mut y = start_y;
-
-        // adapted from ratatui::text::Span::render_ref()
-        for (i, grapheme) in self.line.styled_graphemes(Style::default()).enumerate() {
-            let symbol_width = grapheme.symbol.width();
-            let next_y = y.saturating_add(1);
-            // safety the width of a grapheme should always be much smaller than u16::MAx
-            let next_x = x.saturating_add(u16::try_from(symbol_width).unwrap());
-
-            if next_y > area.bottom() {
-                break;
+    /// Returns the width of all columns of vertical titles, or a single row
+    /// for horizontal titles.
+    fn thickness(&self) -> u16 {
+        if Self::VERTICAL {
+            self.column_width().saturating_mul(self.columns)
+        } else {
+            1
+        }
+    }
+
+    /// Calculates the render area on the side of the title.
+    fn calculate_side_area(&self, area: Rect) -> Rect {
+        if Position::SIDE == Borders::TOP {
+            self.calculate_above_area(area)
+        } else if Position::SIDE == Borders::BOTTOM {
+            self.calculate_below_area(area)
+        } else if Position::SIDE == Borders::LEFT {
+            self.calculate_left_area(area)
+        } else {
+            self.calculate_right_area(area)
+        }
+    }
+
+    /// Calculates the area covered by the text within the render area of
+    /// the title, following the offset or alignment.
+    fn calculate_text_area(&self, area: Rect) -> Rect {
+        if area.is_empty() {
+            return Rect::ZERO;
+        }
+        let (indent, length) = self.place(Self::side_length(area));
+        Self::sub_area(area, indent, length)
+    }
+
+    /// Returns the part of `area` from `offset` to `offset + length` along
+    /// the side of the title.
+    const fn sub_area(area: Rect, offset: u16, length: u16) -> Rect {
+        if Self::VERTICAL {
+            Rect {
+                y: area.y.saturating_add(offset),
+                height: length,
+                ..area
             }
+        } else {
+            Rect {
+                x: area.x.saturating_add(offset),
+                width: length,
+                ..area
+            }
+        }
+    }
+
+    /// Renders the title and its caps within its render `area`.
+    fn render_title(&self, area: Rect, buffer: &mut Buffer) {
+        for (text_area, glyphs) in self.lines(area) {
+            self.render_glyphs(area, text_area, &glyphs, buffer);
+        }
+    }
+
+    /// Renders the text in `text_area` and the caps around it, where they
+    /// fit in `area`.
+    fn render_text(&self, area: Rect, text_area: Rect, buffer: &mut Buffer) {
+        let glyphs = self.fit(self.glyphs(), Self::side_length(text_area));
+        self.render_glyphs(area, text_area, &glyphs, buffer);
+    }
 
-            if i == 0 {
-                // the first grapheme is always set on the cell
-                buffer[(x, y)]
-                    .set_symbol(grapheme.symbol)
-                    .set_style(grapheme.style);
-            } else if y == start_y {
-                // there is one or more zero-width graphemes in the first cell, so the first cell
-                // must be appended to.
-                let mut symbol = buffer[(x, y)].symbol().to_string();
-                symbol.push_str(grapheme.symbol);
-                buffer[(x, y)].set_symbol(&symbol).set_style(grapheme.style);
-            } else if symbol_width == 0 {
-                // append zero-width graphemes to the previous cell
-                let mut symbol = buffer[(x, y)].symbol().to_string();
-                symbol.push_str(grapheme.symbol);
-                buffer[(x, y - 1)]
-                    .set_symbol(&symbol)
-                    .set_style(grapheme.style);
+    /// Renders `glyphs` in `text_area` and the caps around it, where they
+    /// fit in `area`.
+    fn render_glyphs(&self, area: Rect, text_area: Rect, glyphs: &[Glyph], buffer: &mut Buffer) {
+        if !text_area.is_empty() {
+            buffer.set_style(text_area.intersection(buffer.area), self.line.style);
+            if Self::VERTICAL {
+                self.render_vertical(text_area, glyphs, buffer);
             } else {
-                // just a normal grapheme (not first, not zero-width, not overflowing the area)
-                buffer[(x, y)]
-                    .set_symbol(grapheme.symbol)
-                    .set_style(grapheme.style);
+                let (mut x, y) = (text_area.x, text_area.y);
+                for glyph in glyphs {
+                    Self::render_glyph(glyph, x, y, buffer);
+                    x = x.saturating_add(glyph.width);
+                }
             }
-            for x_hidden in (x + 1)..next_x {
-                buffer[(x_hidden, y)].reset();
+        }
+        self.render_caps(area, text_area, buffer);
+    }
+
+    /// Sets the cell at `x`, `y` to `glyph`, resetting the cells hidden by
+    /// wide glyphs.
+    fn render_glyph(glyph: &Glyph, x: u16, y: u16, buffer: &mut Buffer) {
+        if let Some(cell) = buffer.cell_mut((x, y)) {
+            cell.set_symbol(&glyph.symbol).set_style(glyph.style);
+        }
+        for x_hidden in x.saturating_add(1)..x.saturating_add(glyph.width) {
+            if let Some(cell) = buffer.cell_mut((x_hidden, y)) {
+                cell.reset();
             }
-            y = next_y;
         }
     }
+
+    /// Draws the caps on both ends of `text_area`, where they fit in `area`.
+    fn render_caps(&self, area: Rect, text_area: Rect, buffer: &mut Buffer) {
+        use ratatui::layout::Position;
+
+        let Some([start, end]) = self.caps else {
+            return;
+        };
+        let (before, after) = if Self::VERTICAL {
+            let x = Self::edge_column(text_area, 1);
+            (
+                Position::new(x, text_area.y.saturating_sub(1)),
+                Position::new(x, text_area.bottom()),
+            )
+        } else {
+            (
+                Position::new(text_area.x.saturating_sub(1), text_area.y),
+                Position::new(text_area.right(), text_area.y),
+            )
+        };
+        for (position, symbol) in [(before, start), (after, end)] {
+            if !area.contains(position) || text_area.contains(position) {
+                continue;
+            }
+            if let Some(cell) = buffer.cell_mut(position) {
+                cell.set_char(symbol);
+            }
+        }
+    }
+
+    /// Renders text vertically by placing each grapheme on a separate row,
+    /// from the bottom up for reversed titles.
+    ///
+    /// Graphemes narrower than the column are aligned with the edge of the
+    /// area the title is on.
+    fn render_vertical(&self, area: Rect, glyphs: &[Glyph], buffer: &mut Buffer) {
+        for (row, glyph) in (0..area.height).zip(glyphs) {
+            let y = if self.reversed() {
+                area.bottom() - 1 - row
+            } else {
+                area.y + row
+            };
+            let x = Self::edge_column(area, glyph.width);
+            Self::render_glyph(glyph, x, y, buffer);
+        }
+    }
+
+    /// Returns the first column of a grapheme `width` cells wide, aligned
+    /// with the outer edge of the column `area` of a vertical title.
+    const fn edge_column(area: Rect, width: u16) -> u16 {
+        if Position::SIDE.contains(Borders::LEFT) {
+            area.x
+        } else {
+            let x = area.right().saturating_sub(width);
+            if x < area.x { area.x } else { x }
+        }
+    }
+
+    /// Returns the index of the span rendered at `position` when the text
+    /// is rendered in `text_area`.
+    fn span_at(&self, text_area: Rect, position: ratatui::layout::Position) -> Option<usize> {
+        let glyphs = self.fit(self.glyphs(), Self::side_length(text_area));
+        self.glyph_span(text_area, &glyphs, position)
+    }
+
+    /// Returns the index of the span rendered at `position` when the title
+    /// is rendered in `area`.
+    fn span_in(&self, area: Rect, position: ratatui::layout::Position) -> Option<usize> {
+        self.lines(area)
+            .iter()
+            .find_map(|(text_area, glyphs)| self.glyph_span(*text_area, glyphs, position))
+    }
+
+    /// Returns the span of the glyph of `glyphs` rendered at `position`,
+    /// when they are rendered in `text_area`.
+    fn glyph_span(
+        &self,
+        text_area: Rect,
+        glyphs: &[Glyph],
+        position: ratatui::layout::Position,
+    ) -> Option<usize> {
+        if !text_area.contains(position) {
+            return None;
+        }
+        let offset = if !Self::VERTICAL {
+            position.x - text_area.x
+        } else if self.reversed() {
+            text_area.bottom() - 1 - position.y
+        } else {
+            position.y - text_area.y
+        };
+
+        let mut end = 0;
+        glyphs
+            .iter()
+            .find(|glyph| {
+                end += Self::glyph_length(glyph);
+                offset < end
+            })
+            .map(|glyph| glyph.span)
+    }
+
+    /// Hit-tests a title whose text is rendered in `text_area`.
+    fn overlay_hit_test(
+        &self,
+        text_area: Rect,
+        position: ratatui::layout::Position,
+    ) -> Option<HitPart> {
+        self.span_at(text_area, position).map(HitPart::Span)
+    }
+
+    /// Hit-tests a title reserving the part of `area` outside of `inner`,
+    /// returning [`HitPart::Area`] where it isn't covered by the text.
+    fn reserved_hit_test(
+        &self,
+        area: Rect,
+        inner: Rect,
+        render_area: Rect,
+        position: ratatui::layout::Position,
+    ) -> Option<HitPart> {
+        if !area.contains(position) || inner.contains(position) {
+            return None;
+        }
+        self.span_in(render_area, position)
+            .map(HitPart::Span)
+            .or(Some(HitPart::Area))
+    }
+}
+
+/// The symbol replacing the text cut off by truncation.
+const ELLIPSIS: &str = "…";
+
+/// A grapheme of a title and the index of its span.
+#[derive(Debug, Clone)]
+struct Glyph<'a> {
+    span: usize,
+    symbol: Cow<'a, str>,
+    style: Style,
+    width: u16,
 }
 
 // ===== Trait Implementations =====
@@ -532,6 +1240,12 @@ impl<Position: TitlePosition> Styled for Title<'_, Position> {
         Self {
             line: self.line.set_style(style),
             margin: self.margin,
+            offset: self.offset,
+            caps: self.caps,
+            truncation: self.truncation,
+            wide_glyphs: self.wide_glyphs,
+            orientation: self.orientation,
+            columns: self.columns,
             _position: PhantomData,
         }
     }
@@ -542,6 +1256,12 @@ impl<Position: TitlePosition> core::fmt::Debug for Title<'_, Position> {
         f.debug_struct("Title")
             .field("line", &self.line)
             .field("margin", &self.margin)
+            .field("offset", &self.offset)
+            .field("caps", &self.caps)
+            .field("truncation", &self.truncation)
+            .field("wide_glyphs", &self.wide_glyphs)
+            .field("orientation", &self.orientation)
+            .field("columns", &self.columns)
             .field("position", &std::any::type_name::<Position>())
             .finish()
     }
@@ -552,6 +1272,12 @@ impl<Position: TitlePosition> Clone for Title<'_, Position> {
         Self {
             line: self.line.clone(),
             margin: self.margin,
+            offset: self.offset,
+            caps: self.caps,
+            truncation: self.truncation,
+            wide_glyphs: self.wide_glyphs,
+            orientation: self.orientation,
+            columns: self.columns,
             _position: PhantomData,
         }
     }
@@ -562,6 +1288,12 @@ impl<Position: TitlePosition> Default for Title<'_, Position> {
         Self {
             line: Line::default(),
             margin: 0,
+            offset: None,
+            caps: None,
+            truncation: Truncation::Clip,
+            wide_glyphs: WideGlyphs::Widen,
+            orientation: Orientation::TopToBottom,
+            columns: 1,
             _position: PhantomData,
         }
     }
@@ -574,6 +1306,12 @@ impl<'a, Position: TitlePosition> From<Line<'a>> for Title<'a, Position> {
         Self {
             line,
             margin: 0,
+            offset: None,
+            caps: None,
+            truncation: Truncation::Clip,
+            wide_glyphs: WideGlyphs::Widen,
+            orientation: Orientation::TopToBottom,
+            columns: 1,
             _position: PhantomData,
         }
     }
@@ -605,6 +1343,12 @@ impl<'a, Position: TitlePosition> From<Vec<Span<'a>>> for Title<'a, Position> {
                 ..Default::default()
             },
             margin: 0,
+            offset: None,
+            caps: None,
+            truncation: Truncation::Clip,
+            wide_glyphs: WideGlyphs::Widen,
+            orientation: Orientation::TopToBottom,
+            columns: 1,
             _position: PhantomData,
         }
     }
@@ -620,19 +1364,21 @@ impl<'a, Position: TitlePosition> From<Span<'a>> for Title<'a, Position> {
 
 impl RenderModifier for Title<'_, Top> {
     fn after_render(&self, area: Rect, buffer: &mut Buffer) {
-        let render_area = self.calculate_top_area(area);
-        if !render_area.is_empty() {
-            self.line.render_ref(render_area, buffer);
-        }
+        self.render_title(self.calculate_above_area(area), buffer);
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        self.overlay_hit_test(self.calculate_top_area(area), position)
     }
 }
 
 impl RenderModifier for Title<'_, Bottom> {
     fn after_render(&self, area: Rect, buffer: &mut Buffer) {
-        let render_area = self.calculate_bottom_area(area);
-        if !render_area.is_empty() {
-            self.line.render_ref(render_area, buffer);
-        }
+        self.render_title(self.calculate_below_area(area), buffer);
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        self.overlay_hit_test(self.calculate_bottom_area(area), position)
     }
 }
 
@@ -641,7 +1387,7 @@ impl RenderModifier for Title<'_, Above> {
         let render_area = self.calculate_above_area(area);
         if !render_area.is_empty() {
             buffer.set_style(render_area, self.line.style);
-            self.line.render_ref(render_area, buffer);
+            self.render_title(render_area, buffer);
         }
     }
 
@@ -652,6 +1398,19 @@ impl RenderModifier for Title<'_, Above> {
             ..area
         }
     }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        Rect {
+            y: area.y.saturating_sub(1),
+            height: area.height.saturating_add(1),
+            ..area
+        }
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        let render_area = self.calculate_above_area(area);
+        self.reserved_hit_test(area, self.modify_area(area), render_area, position)
+    }
 }
 
 impl RenderModifier for Title<'_, Below> {
@@ -659,7 +1418,7 @@ impl RenderModifier for Title<'_, Below> {
         let render_area = self.calculate_below_area(area);
         if !render_area.is_empty() {
             buffer.set_style(render_area, self.line.style);
-            self.line.render_ref(render_area, buffer);
+            self.render_title(render_area, buffer);
         }
     }
 
@@ -669,31 +1428,39 @@ impl RenderModifier for Title<'_, Below> {
             ..area
         }
     }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        Rect {
+            height: area.height.saturating_add(1),
+            ..area
+        }
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        let render_area = self.calculate_below_area(area);
+        self.reserved_hit_test(area, self.modify_area(area), render_area, position)
+    }
 }
 
 impl RenderModifier for Title<'_, Left> {
     fn after_render(&self, area: Rect, buffer: &mut Buffer) {
-        let render_area = self.calculate_left_area(area);
-        if !render_area.is_empty() {
-            self.render_vertical(
-                render_area,
-                buffer,
-                self.line.alignment.unwrap_or(Alignment::Left),
-            );
-        }
+        self.render_title(self.calculate_left_area(area), buffer);
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        self.span_in(self.calculate_left_area(area), position)
+            .map(HitPart::Span)
     }
 }
 
 impl RenderModifier for Title<'_, Right> {
     fn after_render(&self, area: Rect, buffer: &mut Buffer) {
-        let render_area = self.calculate_right_area(area);
-        if !render_area.is_empty() {
-            self.render_vertical(
-                render_area,
-                buffer,
-                self.line.alignment.unwrap_or(Alignment::Left),
-            );
-        }
+        self.render_title(self.calculate_right_area(area), buffer);
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        self.span_in(self.calculate_right_area(area), position)
+            .map(HitPart::Span)
     }
 }
 
@@ -702,21 +1469,32 @@ impl RenderModifier for Title<'_, Before> {
         let render_area = self.calculate_left_area(area);
         if !render_area.is_empty() {
             buffer.set_style(render_area, self.line.style);
-            self.render_vertical(
-                render_area,
-                buffer,
-                self.line.alignment.unwrap_or(Alignment::Left),
-            );
+            self.render_title(render_area, buffer);
         }
     }
 
     fn modify_area(&self, area: Rect) -> Rect {
+        let width = self.thickness();
         Rect {
-            x: area.x.saturating_add(1),
-            width: area.width.saturating_sub(1),
+            x: area.x.saturating_add(width),
+            width: area.width.saturating_sub(width),
             ..area
         }
     }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        let width = self.thickness();
+        Rect {
+            x: area.x.saturating_sub(width),
+            width: area.width.saturating_add(width),
+            ..area
+        }
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        let render_area = self.calculate_left_area(area);
+        self.reserved_hit_test(area, self.modify_area(area), render_area, position)
+    }
 }
 
 impl RenderModifier for Title<'_, After> {
@@ -724,20 +1502,28 @@ impl RenderModifier for Title<'_, After> {
         let render_area = self.calculate_right_area(area);
         if !render_area.is_empty() {
             buffer.set_style(render_area, self.line.style);
-            self.render_vertical(
-                render_area,
-                buffer,
-                self.line.alignment.unwrap_or(Alignment::Left),
-            );
+            self.render_title(render_area, buffer);
         }
     }
 
     fn modify_area(&self, area: Rect) -> Rect {
         Rect {
-            width: area.width.saturating_sub(1),
+            width: area.width.saturating_sub(self.thickness()),
+            ..area
+        }
+    }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        Rect {
+            width: area.width.saturating_add(self.thickness()),
             ..area
         }
     }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        let render_area = self.calculate_right_area(area);
+        self.reserved_hit_test(area, self.modify_area(area), render_area, position)
+    }
 }
 
 // ===== Optional Serialize/Deserialize (serde) =====
@@ -749,14 +1535,14 @@ impl RenderModifier for Title<'_, After> {
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[cfg(feature = "serde")]
-impl<'a, Position: TitlePosition> Serialize for Title<'a, Position> {
+impl<Position: TitlePosition> Serialize for Title<'_, Position> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
 
-        let mut state = serializer.serialize_struct("Title", 4)?;
+        let mut state = serializer.serialize_struct("Title", 10)?;
 
         // Serialize the spans as a vector of span data
         let spans: Vec<SerializableSpan> = self
@@ -776,102 +1562,69 @@ impl<'a, Position: TitlePosition> Serialize for Title<'a, Position> {
         )?;
         state.serialize_field("style", &self.line.style)?;
         state.serialize_field("margin", &self.margin)?;
+        state.serialize_field("offset", &self.offset)?;
+        state.serialize_field("caps", &self.caps)?;
+        state.serialize_field("truncation", &self.truncation)?;
+        state.serialize_field("wide_glyphs", &self.wide_glyphs)?;
+        state.serialize_field("orientation", &self.orientation)?;
+        state.serialize_field("columns", &self.columns)?;
         state.end()
     }
 }
 
 #[cfg(feature = "serde")]
-impl<'de, 'a, Position: TitlePosition> Deserialize<'de> for Title<'a, Position> {
+impl<'de, Position: TitlePosition> Deserialize<'de> for Title<'_, Position> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
+        // the fields after margin were added later and are optional
         #[derive(Deserialize)]
-        #[serde(field_identifier, rename_all = "lowercase")]
-        enum Field {
-            Spans,
-            Alignment,
-            Style,
-            Margin,
+        #[serde(rename = "Title")]
+        struct SerializableTitle<'a> {
+            spans: Vec<SerializableSpan<'a>>,
+            alignment: Option<SerializableAlignment>,
+            #[serde(deserialize_with = "crate::theme::palette::deserialize_style")]
+            style: Style,
+            margin: u8,
+            #[serde(default)]
+            offset: Option<TitleOffset>,
+            #[serde(default)]
+            caps: Option<[char; 2]>,
+            #[serde(default)]
+            truncation: Truncation,
+            #[serde(default)]
+            wide_glyphs: WideGlyphs,
+            #[serde(default)]
+            orientation: Orientation,
+            #[serde(default = "one_column")]
+            columns: u16,
         }
 
-        struct TitleVisitor<Position>(PhantomData<Position>);
-
-        impl<'de, Position: TitlePosition> serde::de::Visitor<'de> for TitleVisitor<Position> {
-            type Value = Title<'static, Position>;
-
-            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("struct Title")
-            }
-
-            fn visit_map<V>(self, mut map: V) -> Result<Title<'static, Position>, V::Error>
-            where
-                V: serde::de::MapAccess<'de>,
-            {
-                let mut spans = None;
-                let mut alignment = None;
-                let mut style = None;
-                let mut margin = None;
-
-                while let Some(key) = map.next_key()? {
-                    match key {
-                        Field::Spans => {
-                            if spans.is_some() {
-                                return Err(serde::de::Error::duplicate_field("spans"));
-                            }
-                            let serializable_spans: Vec<SerializableSpan> = map.next_value()?;
-                            spans = Some(
-                                serializable_spans
-                                    .into_iter()
-                                    .map(|s| Span::styled(s.content, s.style))
-                                    .collect::<Vec<_>>(),
-                            );
-                        }
-                        Field::Alignment => {
-                            if alignment.is_some() {
-                                return Err(serde::de::Error::duplicate_field("alignment"));
-                            }
-                            let serializable_alignment: Option<SerializableAlignment> =
-                                map.next_value()?;
-                            alignment = serializable_alignment.map(Alignment::from);
-                        }
-
-                        Field::Style => {
-                            if style.is_some() {
-                                return Err(serde::de::Error::duplicate_field("style"));
-                            }
-                            style = Some(map.next_value()?);
-                        }
-                        Field::Margin => {
-                            if margin.is_some() {
-                                return Err(serde::de::Error::duplicate_field("margin"));
-                            }
-                            margin = Some(map.next_value()?);
-                        }
-                    }
-                }
-
-                let spans = spans.ok_or_else(|| serde::de::Error::missing_field("spans"))?;
-                let style = style.ok_or_else(|| serde::de::Error::missing_field("style"))?;
-                let margin = margin.ok_or_else(|| serde::de::Error::missing_field("margin"))?;
-
-                Ok(Title {
-                    line: Line {
-                        spans,
-                        alignment,
-                        style,
-                    },
-                    margin,
-                    _position: PhantomData,
-                })
-            }
+        const fn one_column() -> u16 {
+            1
         }
 
-        deserializer.deserialize_struct(
-            "Title",
-            &["spans", "alignment", "style", "margin"],
-            TitleVisitor(PhantomData),
-        )
+        let title = SerializableTitle::deserialize(deserializer)?;
+        Ok(Title {
+            line: Line {
+                spans: title
+                    .spans
+                    .into_iter()
+                    .map(|span| Span::styled(span.content.into_owned(), span.style))
+                    .collect(),
+                alignment: title.alignment.map(Alignment::from),
+                style: title.style,
+            },
+            margin: title.margin,
+            offset: title.offset,
+            caps: title.caps,
+            truncation: title.truncation,
+            wide_glyphs: title.wide_glyphs,
+            orientation: title.orientation,
+            columns: title.columns.max(1),
+            _position: PhantomData,
+        })
     }
 }
 
@@ -881,6 +1634,7 @@ impl<'de, 'a, Position: TitlePosition> Deserialize<'de> for Title<'a, Position>
 #[cfg(feature = "serde")]
 struct SerializableSpan<'a> {
     content: Cow<'a, str>,
+    #[serde(deserialize_with = "crate::theme::palette::deserialize_style")]
     style: Style,
 }
 
@@ -918,15 +1672,46 @@ impl From<SerializableAlignment> for Alignment {
 // ===== Private Module for Sealed Trait =====
 
 mod private {
-    pub trait Sealed {}
-    impl Sealed for super::Top {}
-    impl Sealed for super::Bottom {}
-    impl Sealed for super::Above {}
-    impl Sealed for super::Below {}
-    impl Sealed for super::Left {}
-    impl Sealed for super::Right {}
-    impl Sealed for super::Before {}
-    impl Sealed for super::After {}
+    use crate::border::Borders;
+
+    pub trait Sealed {
+        /// The side of the area the title is rendered on.
+        const SIDE: Borders;
+        /// Whether the title reserves space next to the area.
+        const RESERVED: bool;
+    }
+    impl Sealed for super::Top {
+        const SIDE: Borders = Borders::TOP;
+        const RESERVED: bool = false;
+    }
+    impl Sealed for super::Bottom {
+        const SIDE: Borders = Borders::BOTTOM;
+        const RESERVED: bool = false;
+    }
+    impl Sealed for super::Above {
+        const SIDE: Borders = Borders::TOP;
+        const RESERVED: bool = true;
+    }
+    impl Sealed for super::Below {
+        const SIDE: Borders = Borders::BOTTOM;
+        const RESERVED: bool = true;
+    }
+    impl Sealed for super::Left {
+        const SIDE: Borders = Borders::LEFT;
+        const RESERVED: bool = false;
+    }
+    impl Sealed for super::Right {
+        const SIDE: Borders = Borders::RIGHT;
+        const RESERVED: bool = false;
+    }
+    impl Sealed for super::Before {
+        const SIDE: Borders = Borders::LEFT;
+        const RESERVED: bool = true;
+    }
+    impl Sealed for super::After {
+        const SIDE: Borders = Borders::RIGHT;
+        const RESERVED: bool = true;
+    }
 }
 
 // ===== Tests =====
@@ -1051,6 +1836,35 @@ mod tests {
         assert_eq!(result, Rect::ZERO); // 10 - (5*2) = 0 width
     }
 
+    #[test]
+    fn aligned_titles_stay_within_margin() {
+        let render = |title: Title<'static, Top>| {
+            let mut buffer = create_test_buffer(12, 1);
+            crate::border::PlainBorder::new(Borders::TOP).before_render(buffer.area, &mut buffer);
+            title.after_render(buffer.area, &mut buffer);
+            buffer
+        };
+        let title = Title::<Top>::raw("ab").margin(2);
+
+        // centered within the space left by the margins, not the whole side
+        assert_eq!(
+            render(title.clone().centered()),
+            Buffer::with_lines(["─────ab─────"])
+        );
+        // right-aligned titles end at the margin instead of past the side
+        assert_eq!(
+            render(title.clone().right_aligned()),
+            Buffer::with_lines(["────────ab──"])
+        );
+        assert_eq!(render(title), Buffer::with_lines(["──ab────────"]));
+
+        let title = Title::<Bottom>::raw("ab").margin(2).right_aligned();
+        assert_eq!(
+            title.calculate_bottom_area(Rect::new(0, 0, 12, 3)),
+            Rect::new(8, 2, 2, 1)
+        );
+    }
+
     #[test]
     fn title_rendering_with_garnish() {
         let mut buffer = create_test_buffer(10, 3);
@@ -1171,6 +1985,241 @@ mod tests {
         assert_eq!(buffer[(2, 9)].symbol(), "Z");
     }
 
+    #[test]
+    fn hit_test_finds_spans() {
+        let area = Rect::new(0, 0, 12, 6);
+        let spans = [Span::raw("ab"), Span::raw("cd")];
+
+        // Centered "abcd" in 12 columns is rendered at x=4..8
+        let top = Title::<Top>::default().spans(spans.clone()).centered();
+        assert_eq!(top.hit_test(area, Position::new(3, 0)), None);
+        assert_eq!(
+            top.hit_test(area, Position::new(5, 0)),
+            Some(HitPart::Span(0))
+        );
+        assert_eq!(
+            top.hit_test(area, Position::new(6, 0)),
+            Some(HitPart::Span(1))
+        );
+        assert_eq!(top.hit_test(area, Position::new(6, 1)), None);
+
+        // Truncated lines skip the start for right alignment
+        let bottom = Title::<Bottom>::default()
+            .spans(spans.clone())
+            .right_aligned();
+        let narrow = Rect::new(0, 0, 3, 2);
+        assert_eq!(
+            bottom.hit_test(narrow, Position::new(0, 1)),
+            Some(HitPart::Span(0))
+        );
+        assert_eq!(
+            bottom.hit_test(narrow, Position::new(1, 1)),
+            Some(HitPart::Span(1))
+        );
+
+        let above = Title::<Above>::default().spans(spans.clone()).margin(1);
+        assert_eq!(
+            above.hit_test(area, Position::new(0, 0)),
+            Some(HitPart::Area)
+        );
+        assert_eq!(
+            above.hit_test(area, Position::new(1, 0)),
+            Some(HitPart::Span(0))
+        );
+        assert_eq!(
+            above.hit_test(area, Position::new(9, 0)),
+            Some(HitPart::Area)
+        );
+        assert_eq!(above.hit_test(area, Position::new(1, 1)), None);
+
+        let left = Title::<Left>::default().spans(spans.clone());
+        assert_eq!(
+            left.hit_test(area, Position::new(0, 1)),
+            Some(HitPart::Span(0))
+        );
+        assert_eq!(
+            left.hit_test(area, Position::new(0, 3)),
+            Some(HitPart::Span(1))
+        );
+        assert_eq!(left.hit_test(area, Position::new(0, 4)), None);
+
+        let after = Title::<After>::default().spans(spans).right_aligned();
+        assert_eq!(
+            after.hit_test(area, Position::new(11, 0)),
+            Some(HitPart::Area)
+        );
+        assert_eq!(
+            after.hit_test(area, Position::new(11, 2)),
+            Some(HitPart::Span(0))
+        );
+        assert_eq!(after.hit_test(area, Position::new(10, 2)), None);
+    }
+
+    #[test]
+    fn vertical_titles_fit_wide_graphemes() {
+        let area = Rect::new(0, 0, 4, 4);
+
+        let mut buffer = create_test_buffer(4, 4);
+        Title::<Right>::raw("a日本").after_render(area, &mut buffer);
+        assert_eq!(buffer[(3, 0)].symbol(), "a");
+        assert_eq!(buffer[(2, 1)].symbol(), "日");
+        assert_eq!(buffer[(2, 2)].symbol(), "本");
+        assert_eq!(buffer[(2, 0)].symbol(), " ");
+
+        let before = Title::<Before>::raw("日本語です").right_aligned();
+        assert_eq!(before.modify_area(area), Rect::new(2, 0, 2, 4));
+        assert_eq!(before.expand_area(Rect::new(2, 0, 2, 4)), area);
+        let mut buffer = create_test_buffer(4, 4);
+        before.before_render(area, &mut buffer);
+        assert_eq!(buffer[(0, 0)].symbol(), "本");
+        assert_eq!(buffer[(0, 3)].symbol(), "す");
+        assert_eq!(
+            before.hit_test(area, Position::new(1, 2)),
+            Some(HitPart::Span(0))
+        );
+
+        // zero-width graphemes join the grapheme before them
+        let mut buffer = create_test_buffer(4, 4);
+        Title::<Left>::raw("e\u{301}x").after_render(area, &mut buffer);
+        assert_eq!(buffer[(0, 0)].symbol(), "e\u{301}");
+        assert_eq!(buffer[(0, 1)].symbol(), "x");
+
+        let mut buffer = create_test_buffer(4, 4);
+        let replaced = Title::<Left>::raw("日x").wide_glyphs(WideGlyphs::Replace('?'));
+        replaced.after_render(area, &mut buffer);
+        assert_eq!(buffer[(0, 0)].symbol(), "?");
+        assert_eq!(buffer[(1, 0)].symbol(), " ");
+        assert_eq!(buffer[(0, 1)].symbol(), "x");
+    }
+
+    #[test]
+    fn reversed_titles_read_upward() {
+        let area = Rect::new(0, 0, 1, 5);
+        let title = Title::<Left>::default()
+            .spans(["a", "bc"])
+            .orientation(Orientation::BottomToTop)
+            .caps('^', 'v');
+
+        let mut buffer = Buffer::empty(area);
+        title.after_render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["^", "c", "b", "a", "v"]));
+        assert_eq!(
+            title.hit_test(area, Position::new(0, 3)),
+            Some(HitPart::Span(0))
+        );
+        assert_eq!(
+            title.hit_test(area, Position::new(0, 1)),
+            Some(HitPart::Span(1))
+        );
+
+        let truncated = Title::<Right>::raw("abcdef")
+            .orientation(Orientation::BottomToTop)
+            .truncation(Truncation::End);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 3));
+        truncated.after_render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["…", "b", "a"]));
+    }
+
+    #[test]
+    fn columns_wrap_long_titles() {
+        let area = Rect::new(0, 0, 6, 5);
+        let title = Title::<After>::raw("one two three").columns(2);
+        assert_eq!(title.modify_area(area), Rect::new(0, 0, 4, 5));
+        assert_eq!(title.expand_area(Rect::new(0, 0, 4, 5)), area);
+
+        let mut buffer = Buffer::empty(area);
+        title.before_render(area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["    to", "    wn", "    oe", "      ", "    t "])
+        );
+        assert_eq!(
+            title.hit_test(area, Position::new(4, 0)),
+            Some(HitPart::Span(0))
+        );
+        assert_eq!(
+            title.hit_test(area, Position::new(5, 4)),
+            Some(HitPart::Area)
+        );
+        assert_eq!(title.hit_test(area, Position::new(3, 0)), None);
+
+        // a zero column count is treated as one
+        assert_eq!(Title::<Before>::raw("x").columns(0).modify_area(area).x, 1);
+    }
+
+    #[test]
+    fn truncation_adds_ellipsis() {
+        let render = |truncation| {
+            let title = Title::<Top>::default()
+                .spans([Span::raw("abc"), Span::raw("def")])
+                .truncation(truncation);
+            let mut buffer = create_test_buffer(5, 1);
+            title.after_render(buffer.area, &mut buffer);
+            buffer
+        };
+
+        assert_eq!(render(Truncation::Clip), Buffer::with_lines(["abcde"]));
+        assert_eq!(render(Truncation::End), Buffer::with_lines(["abcd…"]));
+        assert_eq!(render(Truncation::Middle), Buffer::with_lines(["ab…ef"]));
+        assert_eq!(render(Truncation::Start), Buffer::with_lines(["…cdef"]));
+
+        // the ellipsis belongs to the span it replaces
+        let title = Title::<Top>::default()
+            .spans([Span::raw("abc"), Span::raw("def")])
+            .truncation(Truncation::Middle);
+        let area = Rect::new(0, 0, 5, 1);
+        assert_eq!(
+            title.hit_test(area, Position::new(2, 0)),
+            Some(HitPart::Span(0))
+        );
+        assert_eq!(
+            title.hit_test(area, Position::new(3, 0)),
+            Some(HitPart::Span(1))
+        );
+
+        let vertical = Title::<Left>::raw("abcdef").truncation(Truncation::End);
+        let mut buffer = create_test_buffer(1, 3);
+        vertical.after_render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["a", "b", "…"]));
+    }
+
+    #[test]
+    fn offset_places_labels_between_caps() {
+        let area = Rect::new(0, 0, 20, 8);
+        let mut buffer = Buffer::with_lines(vec!["─".repeat(20); 8]);
+
+        Title::<Top>::raw("ab")
+            .offset(TitleOffset::Absolute(3))
+            .caps('[', ']')
+            .after_render(area, &mut buffer);
+        // percentages are moved back to fit the side
+        Title::<Bottom>::raw("cd")
+            .offset(TitleOffset::Percent(100))
+            .border_caps(BorderSet::plain())
+            .after_render(area, &mut buffer);
+
+        assert_eq!(buffer[(3, 0)].symbol(), "[");
+        assert_eq!(buffer[(4, 0)].symbol(), "a");
+        assert_eq!(buffer[(6, 0)].symbol(), "]");
+        assert_eq!(buffer[(16, 7)].symbol(), "┤");
+        assert_eq!(buffer[(17, 7)].symbol(), "c");
+        assert_eq!(buffer[(19, 7)].symbol(), "├");
+
+        let left = Title::<Left>::raw("xy")
+            .offset(TitleOffset::Percent(50))
+            .border_caps(BorderSet::thick());
+        left.after_render(area, &mut buffer);
+        assert_eq!(buffer[(0, 4)].symbol(), "┻");
+        assert_eq!(buffer[(0, 5)].symbol(), "x");
+        assert_eq!(buffer[(0, 7)].symbol(), "┳");
+
+        assert_eq!(left.hit_test(area, Position::new(0, 4)), None);
+        assert_eq!(
+            left.hit_test(area, Position::new(0, 6)),
+            Some(HitPart::Span(0))
+        );
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn title_serialization() {
@@ -1190,5 +2239,31 @@ mod tests {
 
         let restored: Title<Top> = serde_json::from_str(&json).unwrap();
         assert_eq!(restored, title);
+
+        let label = Title::<Left>::raw("Label")
+            .offset(TitleOffset::Percent(25))
+            .border_caps(BorderSet::double())
+            .wide_glyphs(WideGlyphs::Replace('?'))
+            .orientation(Orientation::BottomToTop)
+            .columns(2);
+        let json = serde_json::to_string(&label).unwrap();
+        let restored: Title<Left> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, label);
+
+        // fields added after the first release are optional
+        let mut value = serde_json::to_value(Title::<Top>::raw("Old")).unwrap();
+        value.as_object_mut().unwrap().retain(|key, _| {
+            ![
+                "offset",
+                "caps",
+                "truncation",
+                "wide_glyphs",
+                "orientation",
+                "columns",
+            ]
+            .contains(&key.as_str())
+        });
+        let restored: Title<Top> = serde_json::from_value(value).unwrap();
+        assert_eq!(restored, Title::raw("Old"));
     }
 }
@@ -0,0 +1,372 @@
+use std::fmt;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Position, Rect},
+    style::Style,
+};
+
+use super::{Title, TitlePosition};
+use crate::{HitPart, Padding, RenderModifier, border::Borders};
+
+/// Titles sharing a side, laid out so they don't overlap.
+///
+/// Every title is placed where it would be on its own, following its
+/// alignment or offset. The titles are placed in order: a title colliding
+/// with titles placed before it moves into the free space nearest to its
+/// place and is shortened with its [`Truncation`](super::Truncation)
+/// strategy. Titles without room for at least one cell of text are left
+/// out. The first titles have the highest priority and keep their width
+/// the longest.
+///
+/// For reserved positions the group reserves a single line or column for
+/// all its titles, styled with the style of the group. Vertical groups
+/// reserve a column as wide as the widest title needs.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect, text::Text, widgets::Widget};
+/// use ratatui_garnish::{
+///     GarnishableWidget,
+///     border::PlainBorder,
+///     title::{Title, TitleGroup, Top, Truncation},
+/// };
+///
+/// let titles = TitleGroup::new([
+///     Title::<Top>::raw(" Status ").right_aligned().margin(1),
+///     Title::<Top>::raw(" ~/projects/garnish ")
+///         .truncation(Truncation::Start)
+///         .margin(1),
+/// ]);
+/// let widget = Text::raw("").garnish(titles).garnish(PlainBorder::default());
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 2));
+/// widget.render(buffer.area, &mut buffer);
+/// assert_eq!(buffer, Buffer::with_lines(["┌…/garnish  Status ┐", "└──────────────────┘"]));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "", default))]
+#[derive(PartialEq, Eq, Hash)]
+pub struct TitleGroup<'a, P: TitlePosition> {
+    titles: Vec<Title<'a, P>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_style")
+    )]
+    style: Style,
+}
+
+impl<'a, P: TitlePosition> TitleGroup<'a, P> {
+    /// Creates a group of titles, in order of priority.
+    #[must_use = "constructor returns a new instance"]
+    pub fn new<I>(titles: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Title<'a, P>>,
+    {
+        Self {
+            titles: titles.into_iter().map(Into::into).collect(),
+            style: Style::default(),
+        }
+    }
+
+    /// Adds a title with a lower priority than the titles before it.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub fn title<T: Into<Title<'a, P>>>(mut self, title: T) -> Self {
+        self.titles.push(title.into());
+        self
+    }
+
+    /// Sets the style of the line or column reserved by reserved positions.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the titles of the group, in order of priority.
+    #[must_use]
+    pub fn titles(&self) -> &[Title<'a, P>] {
+        &self.titles
+    }
+
+    /// Returns the part of its side each title is rendered in, including
+    /// the caps, or `None` for titles without room.
+    fn layout(&self, area: Rect) -> Vec<Option<Rect>> {
+        let mut taken: Vec<(u16, u16)> = Vec::new();
+        self.titles
+            .iter()
+            .map(|title| {
+                let side = title.calculate_side_area(area);
+                let (side_start, side_length) = if Title::<P>::VERTICAL {
+                    (side.y, side.height)
+                } else {
+                    (side.x, side.width)
+                };
+                let caps = title.caps_length();
+                let (indent, length) = title.place(side_length);
+                let start = side_start.saturating_add(indent).saturating_sub(caps / 2);
+                let length = length.saturating_add(caps);
+                let centered =
+                    title.offset.is_none() && title.line.alignment == Some(Alignment::Center);
+                let right =
+                    title.offset.is_none() && title.line.alignment == Some(Alignment::Right);
+                let anchor = if centered {
+                    start.saturating_add(length / 2)
+                } else if right != title.reversed() {
+                    // reversed titles start at the bottom
+                    start.saturating_add(length.saturating_sub(1))
+                } else {
+                    start
+                };
+
+                let (gap_start, gap_end) =
+                    gaps(side_start, side_start.saturating_add(side_length), &taken)
+                        .into_iter()
+                        .min_by_key(|&(gap_start, gap_end)| {
+                            gap_start.saturating_sub(anchor) + anchor.saturating_sub(gap_end - 1)
+                        })?;
+                if gap_end - gap_start <= caps {
+                    return None;
+                }
+                let length = length.min(gap_end - gap_start);
+                let start = start.clamp(gap_start, gap_end - length);
+                taken.push((start, start.saturating_add(length)));
+                Some(Title::<P>::sub_area(side, start - side_start, length))
+            })
+            .collect()
+    }
+
+    /// Renders the titles over or next to `area`.
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        for (title, segment) in self.titles.iter().zip(self.layout(area)) {
+            if let Some(segment) = segment {
+                title.render_text(segment, text_area(title, segment), buffer);
+            }
+        }
+    }
+
+    /// Returns the space reserved next to the area.
+    fn reserved(&self) -> Padding {
+        let size = self
+            .titles
+            .iter()
+            .map(Title::column_width)
+            .max()
+            .unwrap_or(1);
+        if !P::RESERVED {
+            Padding::default()
+        } else if P::SIDE.contains(Borders::TOP) {
+            Padding::top(1)
+        } else if P::SIDE.contains(Borders::BOTTOM) {
+            Padding::bottom(1)
+        } else if P::SIDE.contains(Borders::LEFT) {
+            Padding::left(size)
+        } else {
+            Padding::right(size)
+        }
+    }
+}
+
+/// Returns the part of `segment` covered by the text of `title`.
+const fn text_area<P: TitlePosition>(title: &Title<'_, P>, segment: Rect) -> Rect {
+    let caps = title.caps_length();
+    let length = Title::<P>::side_length(segment).saturating_sub(caps);
+    Title::<P>::sub_area(segment, caps / 2, length)
+}
+
+/// Returns the ranges from `start` to `end` not covered by `taken`.
+fn gaps(start: u16, end: u16, taken: &[(u16, u16)]) -> Vec<(u16, u16)> {
+    let mut taken = taken.to_vec();
+    taken.sort_unstable();
+
+    let mut gaps = Vec::new();
+    let mut cursor = start;
+    for (taken_start, taken_end) in taken {
+        if cursor < taken_start.min(end) {
+            gaps.push((cursor, taken_start.min(end)));
+        }
+        cursor = cursor.max(taken_end);
+    }
+    if cursor < end {
+        gaps.push((cursor, end));
+    }
+    gaps
+}
+
+impl<P: TitlePosition> RenderModifier for TitleGroup<'_, P> {
+    fn before_render(&self, area: Rect, buffer: &mut Buffer) {
+        if P::RESERVED {
+            let inner = self.modify_area(area);
+            for position in area.intersection(buffer.area).positions() {
+                if !inner.contains(position) {
+                    buffer[position].set_style(self.style);
+                }
+            }
+            self.render(area, buffer);
+        }
+    }
+
+    fn after_render(&self, area: Rect, buffer: &mut Buffer) {
+        if !P::RESERVED {
+            self.render(area, buffer);
+        }
+    }
+
+    fn modify_area(&self, area: Rect) -> Rect {
+        self.reserved().modify_area(area)
+    }
+
+    fn expand_area(&self, area: Rect) -> Rect {
+        self.reserved().expand_area(area)
+    }
+
+    fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+        let layout = self.layout(area);
+        for (index, (title, segment)) in self.titles.iter().zip(layout).enumerate() {
+            let Some(segment) = segment else {
+                continue;
+            };
+            if let Some(span) = title.span_at(text_area(title, segment), position) {
+                return Some(HitPart::TitleSpan { title: index, span });
+            }
+        }
+        let reserved =
+            P::RESERVED && area.contains(position) && !self.modify_area(area).contains(position);
+        reserved.then_some(HitPart::Area)
+    }
+}
+
+impl<P: TitlePosition> fmt::Debug for TitleGroup<'_, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TitleGroup")
+            .field("titles", &self.titles)
+            .field("style", &self.style)
+            .finish()
+    }
+}
+
+impl<P: TitlePosition> Clone for TitleGroup<'_, P> {
+    fn clone(&self) -> Self {
+        Self {
+            titles: self.titles.clone(),
+            style: self.style,
+        }
+    }
+}
+
+impl<P: TitlePosition> Default for TitleGroup<'_, P> {
+    /// Creates an empty group.
+    fn default() -> Self {
+        Self {
+            titles: Vec::new(),
+            style: Style::default(),
+        }
+    }
+}
+
+impl<'a, P: TitlePosition> FromIterator<Title<'a, P>> for TitleGroup<'a, P> {
+    fn from_iter<I: IntoIterator<Item = Title<'a, P>>>(titles: I) -> Self {
+        Self::new(titles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::title::{Above, Left, Top, Truncation};
+
+    #[test]
+    fn titles_shrink_by_priority() {
+        let area = Rect::new(0, 0, 16, 3);
+        let group = TitleGroup::new([
+            Title::<Top>::raw("centered").centered(),
+            Title::<Top>::raw("left title").truncation(Truncation::End),
+            Title::<Top>::raw("right")
+                .right_aligned()
+                .truncation(Truncation::End),
+        ]);
+
+        let mut buffer = Buffer::empty(area);
+        group.after_render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["lef…centeredrig…", "", ""]));
+
+        assert_eq!(
+            group.hit_test(area, Position::new(5, 0)),
+            Some(HitPart::TitleSpan { title: 0, span: 0 })
+        );
+        assert_eq!(
+            group.hit_test(area, Position::new(3, 0)),
+            Some(HitPart::TitleSpan { title: 1, span: 0 })
+        );
+        assert_eq!(
+            group.hit_test(area, Position::new(14, 0)),
+            Some(HitPart::TitleSpan { title: 2, span: 0 })
+        );
+        assert_eq!(group.hit_test(area, Position::new(5, 1)), None);
+    }
+
+    #[test]
+    fn reserved_groups_reserve_one_line() {
+        let area = Rect::new(0, 0, 10, 4);
+        let group = TitleGroup::new([
+            Title::<Above>::raw("a"),
+            Title::<Above>::raw("b").right_aligned(),
+        ])
+        .style(Style::new().bg(ratatui::style::Color::Blue));
+
+        assert_eq!(group.modify_area(area), Rect::new(0, 1, 10, 3));
+        assert_eq!(group.expand_area(Rect::new(0, 1, 10, 3)), area);
+
+        let mut buffer = Buffer::empty(area);
+        group.before_render(area, &mut buffer);
+        assert_eq!(buffer[(0, 0)].symbol(), "a");
+        assert_eq!(buffer[(9, 0)].symbol(), "b");
+        assert_eq!(buffer[(5, 0)].bg, ratatui::style::Color::Blue);
+        assert_eq!(
+            group.hit_test(area, Position::new(5, 0)),
+            Some(HitPart::Area)
+        );
+        assert_eq!(group.hit_test(area, Position::new(5, 1)), None);
+
+        let vertical = TitleGroup::new([Title::<Left>::raw("ab"), Title::<Left>::raw("cd")]);
+        let mut buffer = Buffer::empty(area);
+        vertical.after_render(area, &mut buffer);
+        assert_eq!(buffer[(0, 1)].symbol(), "b");
+        assert_eq!(buffer[(0, 2)].symbol(), "c");
+    }
+
+    #[test]
+    fn layout_saturates_at_the_edge_of_the_coordinates() {
+        // an area running past `u16::MAX`, as a struct literal allows
+        let area = Rect {
+            x: u16::MAX - 4,
+            y: 0,
+            width: 12,
+            height: 1,
+        };
+        let group = TitleGroup::new([
+            Title::<Top>::raw("abc").centered().caps('[', ']'),
+            Title::<Top>::raw("de").right_aligned(),
+            Title::<Top>::raw("f").margin(2),
+        ]);
+        let layout = group.layout(area);
+        assert_eq!(layout.len(), 3);
+        for segment in layout.into_iter().flatten() {
+            assert!(segment.x >= area.x);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn title_group_serialization() {
+        let group = TitleGroup::new([
+            Title::<Top>::raw("one").truncation(Truncation::Middle),
+            Title::<Top>::raw("two").right_aligned(),
+        ]);
+        let json = serde_json::to_string(&group).unwrap();
+        let restored: TitleGroup<Top> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, group);
+    }
+}
@@ -0,0 +1,420 @@
+use std::{borrow::Cow, fmt};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::Style,
+    text::Text,
+};
+
+use super::{Above, After, Before, Below, Glyph, Orientation, Title, TitlePosition};
+use crate::{HitPart, Padding, RenderModifier, border::Borders};
+
+/// A title of several lines, reserving a row for every line above or below
+/// the widget, or a column before or after it.
+///
+/// Lines too long for the width (or height) of the area wrap into the next
+/// row (or column), breaking at whitespace where possible. Every line is a
+/// [`Title`] with its own alignment, margin and style; the style of the
+/// text fills the whole reserved space.
+///
+/// Columns follow each other like the lines of turned text, see
+/// [`Title::columns`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect, text::Text, widgets::Widget};
+/// use ratatui_garnish::{GarnishableWidget, title::{Above, TextTitle}};
+///
+/// let header = TextTitle::<Above>::from(Text::raw("Quarterly report\nSales by region").centered());
+/// let widget = Text::raw("content").garnish(header);
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 5));
+/// widget.render(buffer.area, &mut buffer);
+/// assert_eq!(
+///     buffer,
+///     Buffer::with_lines([" Quarterly  ", "   report   ", "  Sales by  ", "   region   ", "content     "])
+/// );
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "", default))]
+#[derive(PartialEq, Eq, Hash)]
+pub struct TextTitle<'a, P: TitlePosition> {
+    lines: Vec<Title<'a, P>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::theme::palette::deserialize_style")
+    )]
+    style: Style,
+    orientation: Orientation,
+}
+
+impl<'a, P: TitlePosition> TextTitle<'a, P> {
+    /// Creates a title from its lines.
+    #[must_use = "constructor returns a new instance"]
+    pub fn new<I>(lines: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Title<'a, P>>,
+    {
+        Self {
+            lines: lines.into_iter().map(Into::into).collect(),
+            style: Style::default(),
+            orientation: Orientation::TopToBottom,
+        }
+    }
+
+    /// Creates a title from a string, with a line for every line of the
+    /// string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_garnish::title::{Below, TextTitle};
+    ///
+    /// let footer = TextTitle::<Below>::raw("Page 1 of 3\nPress q to quit");
+    /// assert_eq!(footer.lines().len(), 2);
+    /// ```
+    #[must_use = "constructor returns a new instance"]
+    pub fn raw<T: Into<Cow<'a, str>>>(content: T) -> Self {
+        Text::raw(content).into()
+    }
+
+    /// Adds a line after the lines of the title, with the reading direction
+    /// of the title.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub fn line<T: Into<Title<'a, P>>>(mut self, line: T) -> Self {
+        self.lines.push(line.into().orientation(self.orientation));
+        self
+    }
+
+    /// Sets the style of the space reserved by the title.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the reading direction of all lines of a vertical title, which
+    /// also decides the order of the columns.
+    #[must_use = "method returns a new instance and does not mutate the original"]
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self.lines = self
+            .lines
+            .into_iter()
+            .map(|line| line.orientation(orientation))
+            .collect();
+        self
+    }
+
+    /// Returns the lines of the title.
+    #[must_use]
+    pub fn lines(&self) -> &[Title<'a, P>] {
+        &self.lines
+    }
+
+    /// Returns the rows (or columns) the lines take in `area` after
+    /// wrapping, with the index of their line and their width (or height).
+    fn wrap(&self, area: Rect) -> Vec<(usize, u16, Vec<Glyph<'_>>)> {
+        let mut wrapped = Vec::new();
+        for (index, line) in self.lines.iter().enumerate() {
+            let side = line.calculate_side_area(area);
+            let length = Title::<P>::side_length(side).saturating_sub(line.caps_length());
+            let thickness = line.column_width();
+            if length == 0 {
+                continue;
+            }
+            for glyphs in line.wrap(length, usize::MAX) {
+                wrapped.push((index, thickness, glyphs));
+            }
+        }
+        wrapped
+    }
+
+    /// Returns the rows (or columns) rendered in `area` with the index of
+    /// their line, after the margin of the line.
+    fn layout(&self, area: Rect) -> Vec<(usize, Rect, Vec<Glyph<'_>>)> {
+        let wrapped = self.wrap(area);
+        let size = Self::size(&wrapped, area);
+        let mut offset = 0u16;
+        let mut layout = Vec::new();
+        for (index, thickness, glyphs) in wrapped {
+            if offset.saturating_add(thickness) > size {
+                break;
+            }
+            let line = &self.lines[index];
+            let slot = if Title::<P>::VERTICAL {
+                let block_x = if P::SIDE.contains(Borders::LEFT) {
+                    area.x
+                } else {
+                    area.right() - size
+                };
+                let x = if self.orientation == Orientation::BottomToTop {
+                    block_x + offset
+                } else {
+                    block_x + size - offset - thickness
+                };
+                line.calculate_vertical_area(area, x, thickness)
+            } else {
+                let block_y = if P::SIDE.contains(Borders::TOP) {
+                    area.y
+                } else {
+                    area.bottom() - size
+                };
+                line.calculate_horizontal_full_area(area, block_y + offset)
+            };
+            offset += thickness;
+            layout.push((index, slot, glyphs));
+        }
+        layout
+    }
+
+    /// Returns the number of rows (or columns) reserved for `wrapped`
+    /// lines, at most the height (or width) of `area`.
+    fn size(wrapped: &[(usize, u16, Vec<Glyph<'_>>)], area: Rect) -> u16 {
+        Self::unclamped_size(wrapped).min(if Title::<P>::VERTICAL {
+            area.width
+        } else {
+            area.height
+        })
+    }
+
+    /// Returns the number of rows (or columns) `wrapped` lines take.
+    fn unclamped_size(wrapped: &[(usize, u16, Vec<Glyph<'_>>)]) -> u16 {
+        wrapped.iter().fold(0u16, |size, (_, thickness, _)| {
+            size.saturating_add(*thickness)
+        })
+    }
+
+    /// Returns the space reserved in `area`.
+    fn reserved(&self, area: Rect) -> Padding {
+        Self::padding(Self::size(&self.wrap(area), area))
+    }
+
+    /// Returns the space needed next to `area` for all lines to fit.
+    fn needed(&self, area: Rect) -> Padding {
+        Self::padding(Self::unclamped_size(&self.wrap(area)))
+    }
+
+    /// Returns the padding of `size` rows (or columns) on the side of the
+    /// title.
+    const fn padding(size: u16) -> Padding {
+        if P::SIDE.contains(Borders::TOP) {
+            Padding::top(size)
+        } else if P::SIDE.contains(Borders::BOTTOM) {
+            Padding::bottom(size)
+        } else if P::SIDE.contains(Borders::LEFT) {
+            Padding::left(size)
+        } else {
+            Padding::right(size)
+        }
+    }
+
+    /// Styles the reserved space and renders the lines in it.
+    fn render(&self, area: Rect, buffer: &mut Buffer) {
+        let inner = self.reserved(area).modify_area(area);
+        for position in area.intersection(buffer.area).positions() {
+            if !inner.contains(position) {
+                buffer[position].set_style(self.style);
+            }
+        }
+        for (index, slot, glyphs) in self.layout(area) {
+            let line = &self.lines[index];
+            buffer.set_style(slot, line.line.style);
+            let (text_area, glyphs) = line.place_glyphs(slot, glyphs);
+            line.render_glyphs(slot, text_area, &glyphs, buffer);
+        }
+    }
+
+    /// Hit-tests the lines, returning [`HitPart::Area`] for the reserved
+    /// space not covered by text.
+    fn hit(&self, area: Rect, position: Position) -> Option<HitPart> {
+        if !area.contains(position) || self.reserved(area).modify_area(area).contains(position) {
+            return None;
+        }
+        self.layout(area)
+            .into_iter()
+            .find_map(|(index, slot, glyphs)| {
+                let line = &self.lines[index];
+                let (text_area, glyphs) = line.place_glyphs(slot, glyphs);
+                line.glyph_span(text_area, &glyphs, position)
+                    .map(|span| HitPart::TitleSpan { title: index, span })
+            })
+            .or(Some(HitPart::Area))
+    }
+}
+
+macro_rules! reserved_text_title {
+    ($($position:ty),+) => {
+        $(
+            impl RenderModifier for TextTitle<'_, $position> {
+                fn before_render(&self, area: Rect, buffer: &mut Buffer) {
+                    self.render(area, buffer);
+                }
+
+                fn modify_area(&self, area: Rect) -> Rect {
+                    self.reserved(area).modify_area(area)
+                }
+
+                fn expand_area(&self, area: Rect) -> Rect {
+                    self.needed(area).expand_area(area)
+                }
+
+                fn hit_test(&self, area: Rect, position: Position) -> Option<HitPart> {
+                    self.hit(area, position)
+                }
+            }
+        )+
+    };
+}
+
+reserved_text_title!(Above, Below, Before, After);
+
+impl<P: TitlePosition> fmt::Debug for TextTitle<'_, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextTitle")
+            .field("lines", &self.lines)
+            .field("style", &self.style)
+            .field("orientation", &self.orientation)
+            .finish()
+    }
+}
+
+impl<P: TitlePosition> Clone for TextTitle<'_, P> {
+    fn clone(&self) -> Self {
+        Self {
+            lines: self.lines.clone(),
+            style: self.style,
+            orientation: self.orientation,
+        }
+    }
+}
+
+impl<P: TitlePosition> Default for TextTitle<'_, P> {
+    /// Creates a title without lines.
+    fn default() -> Self {
+        Self::new(Vec::<Title<P>>::new())
+    }
+}
+
+impl<'a, P: TitlePosition> From<Text<'a>> for TextTitle<'a, P> {
+    /// Creates a title with a line for every line of `text`, lines without
+    /// an alignment take the alignment of the text.
+    fn from(text: Text<'a>) -> Self {
+        let alignment = text.alignment;
+        Self::new(text.lines.into_iter().map(|mut line| {
+            line.alignment = line.alignment.or(alignment);
+            Title::from(line)
+        }))
+        .style(text.style)
+    }
+}
+
+impl<'a, P: TitlePosition> FromIterator<Title<'a, P>> for TextTitle<'a, P> {
+    fn from_iter<I: IntoIterator<Item = Title<'a, P>>>(lines: I) -> Self {
+        Self::new(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::*;
+
+    #[test]
+    fn lines_reserve_rows_and_wrap() {
+        let area = Rect::new(0, 0, 8, 5);
+        let title =
+            TextTitle::<Below>::raw("one two three\nfour").style(Style::new().bg(Color::Blue));
+        assert_eq!(title.modify_area(area), Rect::new(0, 0, 8, 2));
+        assert_eq!(title.expand_area(Rect::new(0, 0, 8, 2)), area);
+
+        let mut buffer = Buffer::empty(area);
+        title.before_render(area, &mut buffer);
+        assert_eq!(buffer[(0, 2)].symbol(), "o");
+        assert_eq!(buffer[(0, 3)].symbol(), "t");
+        assert_eq!(buffer[(0, 4)].symbol(), "f");
+        assert_eq!(buffer[(7, 4)].bg, Color::Blue);
+        assert_eq!(buffer[(7, 1)].bg, Color::Reset);
+
+        assert_eq!(
+            title.hit_test(area, Position::new(0, 3)),
+            Some(HitPart::TitleSpan { title: 0, span: 0 })
+        );
+        assert_eq!(
+            title.hit_test(area, Position::new(0, 4)),
+            Some(HitPart::TitleSpan { title: 1, span: 0 })
+        );
+        assert_eq!(
+            title.hit_test(area, Position::new(6, 4)),
+            Some(HitPart::Area)
+        );
+        assert_eq!(title.hit_test(area, Position::new(0, 1)), None);
+
+        // lines that don't fit are left out
+        let small = Rect::new(0, 0, 8, 2);
+        let title = TextTitle::<Above>::raw("a\nb\nc");
+        assert_eq!(title.modify_area(small), Rect::new(0, 2, 8, 0));
+        let mut buffer = Buffer::empty(small);
+        title.before_render(small, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["a       ", "b       "]));
+    }
+
+    #[test]
+    fn lines_without_room_reserve_nothing() {
+        let area = Rect::new(0, 0, 2, 5);
+        let title = TextTitle::<Above>::new([Title::raw("Quarterly report").caps('[', ']')]);
+        assert_eq!(title.modify_area(area), area);
+        let mut buffer = Buffer::empty(area);
+        title.before_render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::empty(area));
+        assert_eq!(title.hit_test(area, Position::new(0, 0)), None);
+
+        let title = TextTitle::<After>::new([Title::raw("ab").margin(3), Title::raw("c")]);
+        assert_eq!(title.modify_area(area), Rect::new(0, 0, 1, 5));
+    }
+
+    #[test]
+    fn lines_reserve_columns() {
+        let area = Rect::new(0, 0, 5, 3);
+        let title = TextTitle::<Before>::raw("ab\ncd");
+        assert_eq!(title.modify_area(area), Rect::new(2, 0, 3, 3));
+
+        let mut buffer = Buffer::empty(area);
+        title.before_render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["ca   ", "db   ", "     "]));
+
+        let mut buffer = Buffer::empty(area);
+        let upward = title.orientation(Orientation::BottomToTop);
+        upward.before_render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["     ", "bd   ", "ac   "]));
+
+        // lines added later read in the same direction
+        let mut buffer = Buffer::empty(area);
+        let upward = TextTitle::<Before>::raw("ab")
+            .orientation(Orientation::BottomToTop)
+            .line("cd");
+        upward.before_render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["     ", "bd   ", "ac   "]));
+
+        let area = Rect::new(0, 0, 5, 2);
+        let wrapped = TextTitle::<After>::raw("abcd");
+        assert_eq!(wrapped.modify_area(area), Rect::new(0, 0, 3, 2));
+        let mut buffer = Buffer::empty(area);
+        wrapped.before_render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["   ca", "   db"]));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn text_title_serialization() {
+        let title = TextTitle::<Above>::from(Text::raw("Report\nQ3").right_aligned())
+            .style(Style::new().bg(Color::Blue));
+        let json = serde_json::to_string(&title).unwrap();
+        let restored: TextTitle<Above> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, title);
+    }
+}